@@ -0,0 +1,183 @@
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The answer to one part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    /// An integral answer of any width.
+    Number(i128),
+    /// A textual answer such as a coordinate or a code word.
+    Text(String),
+    /// The part has not been solved yet.
+    Unsolved,
+}
+
+impl Answer {
+    /// Determines if the answer holds a value.
+    pub fn is_solved(&self) -> bool {
+        !matches!(self, Answer::Unsolved)
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Number(x) => write!(f, "{}", x),
+            Answer::Text(x) => write!(f, "{}", x),
+            Answer::Unsolved => write!(f, "unsolved"),
+        }
+    }
+}
+
+/// Parses an answer as it would be typed into the site.
+///
+/// Integral text becomes a [`Answer::Number`], blank text is [`Answer::Unsolved`] and anything else
+/// is kept verbatim as [`Answer::Text`].
+impl FromStr for Answer {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(match s.parse::<i128>() {
+            Ok(x) => Answer::Number(x),
+            Err(_) if s.is_empty() => Answer::Unsolved,
+            Err(_) => Answer::Text(s.to_string()),
+        })
+    }
+}
+
+/// Numbers are ordered numerically and text lexically.  Answers of differing kinds are not comparable.
+impl PartialOrd for Answer {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Answer::Number(x), Answer::Number(y)) => Some(x.cmp(y)),
+            (Answer::Text(x), Answer::Text(y)) => Some(x.cmp(y)),
+            (Answer::Unsolved, Answer::Unsolved) => Some(Ordering::Equal),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Number(value as i128)
+                }
+            }
+
+            impl PartialEq<$t> for Answer {
+                fn eq(&self, other: &$t) -> bool {
+                    matches!(self, Answer::Number(x) if *x == *other as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+/// A `u128` which fits in an `i128` becomes a [`Answer::Number`], larger values are kept as their
+/// digits in [`Answer::Text`] so nothing is lost.
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(x) => Answer::Number(x),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl PartialEq<u128> for Answer {
+    fn eq(&self, other: &u128) -> bool {
+        match (self, i128::try_from(*other)) {
+            (Answer::Number(x), Ok(y)) => *x == y,
+            (Answer::Text(x), Err(_)) => *x == other.to_string(),
+            _ => false,
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+impl<T: Into<Answer>> From<Option<T>> for Answer {
+    fn from(value: Option<T>) -> Self {
+        match value {
+            Some(x) => x.into(),
+            None => Answer::Unsolved,
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        match self {
+            Answer::Number(x) => other.parse::<i128>().is_ok_and(|y| *x == y),
+            Answer::Text(x) => x == other,
+            Answer::Unsolved => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn integers_of_any_width_are_equal() {
+        assert_eq!(Answer::from(42u8), Answer::from(42i64));
+        assert_eq!(Answer::from(42usize), 42);
+        assert_eq!(Answer::from(u64::MAX), u64::MAX);
+    }
+
+    #[test]
+    fn u128_is_checked_against_i128() {
+        assert_eq!(Answer::from(42u128), Answer::Number(42));
+        assert_eq!(Answer::from(42u128), 42u128);
+        assert_eq!(Answer::from(u128::MAX), Answer::Text(u128::MAX.to_string()));
+        assert_eq!(Answer::from(u128::MAX), u128::MAX);
+    }
+
+    #[test]
+    fn display_is_plain() {
+        assert_eq!(Answer::from(-7).to_string(), "-7");
+        assert_eq!(Answer::from("6,1").to_string(), "6,1");
+        assert_eq!(Answer::Unsolved.to_string(), "unsolved");
+    }
+
+    #[test]
+    fn parse_recognizes_kind() {
+        assert_eq!("1234".parse::<Answer>().unwrap(), Answer::Number(1234));
+        assert_eq!(" 6,1\n".parse::<Answer>().unwrap(), Answer::Text("6,1".to_string()));
+        assert_eq!("".parse::<Answer>().unwrap(), Answer::Unsolved);
+    }
+
+    #[test]
+    fn numbers_are_ordered_numerically() {
+        let (nine, ten) = (Answer::from(9), Answer::from(10));
+        assert!(nine < ten);
+
+        let (nine, ten) = (Answer::from("9"), Answer::from("10"));
+        assert!(nine > ten);
+
+        assert_eq!(Answer::from(9).partial_cmp(&Answer::from("9")), None);
+    }
+
+    #[test]
+    fn option_maps_to_unsolved() {
+        assert_eq!(Answer::from(None::<i32>), Answer::Unsolved);
+        assert_eq!(Answer::from(Some(3)), 3);
+    }
+}
@@ -0,0 +1,332 @@
+use crate::{build_file_name, session, unlock, AocError, Context};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// How a cached input was retrieved, kept as `{year}.day{day}.meta` next to the input with one tab
+/// separated `key value` line per field.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Metadata {
+    /// When the input was downloaded.
+    pub fetched_at: SystemTime,
+    /// The profile the input was downloaded for, `None` for the default account.
+    pub profile: Option<String>,
+    /// The length of the input in bytes.
+    pub length: u64,
+    /// The SHA-256 digest of the input as lowercase hex.
+    pub sha256: String,
+    /// The HTTP status the site answered with.
+    pub status: u16,
+}
+
+impl Metadata {
+    /// Describes an input which has just been downloaded.
+    pub fn new(body: &str, status: u16, context: &Context) -> Metadata {
+        Metadata {
+            fetched_at: context.clock.now(),
+            profile: context.profile.clone(),
+            length: body.len() as u64,
+            sha256: digest(body),
+            status,
+        }
+    }
+
+    fn to_record(&self) -> String {
+        let fetched_at = self.fetched_at.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+        format!(
+            "fetched_at\t{}\nprofile\t{}\nlength\t{}\nsha256\t{}\nstatus\t{}\n",
+            fetched_at,
+            self.profile.as_deref().unwrap_or(""),
+            self.length,
+            self.sha256,
+            self.status
+        )
+    }
+
+    fn parse(record: &str) -> Result<Metadata, String> {
+        let field = |key: &str| record.lines().find_map(|line| line.strip_prefix(key)?.strip_prefix('\t')).ok_or_else(|| format!("Missing {}", key));
+        let number = |key: &str| field(key)?.parse::<u64>().map_err(|e| format!("Invalid {}: {}", key, e));
+
+        Ok(Metadata {
+            fetched_at: UNIX_EPOCH + Duration::from_secs(number("fetched_at")?),
+            profile: Some(field("profile")?).filter(|p| !p.is_empty()).map(|p| p.to_string()),
+            length: number("length")?,
+            sha256: field("sha256")?.to_string(),
+            status: field("status")?.parse::<u16>().map_err(|e| format!("Invalid status: {}", e))?,
+        })
+    }
+}
+
+/// Whether a cached input can be trusted.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Integrity {
+    /// The input matches its metadata.
+    Valid,
+    /// The input was cached before metadata was recorded so it cannot be checked.
+    Unverified,
+    /// The input must not be used, for the reason given.
+    Invalid(String),
+}
+
+impl fmt::Display for Integrity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Integrity::Valid => write!(f, "valid"),
+            Integrity::Unverified => write!(f, "unverified"),
+            Integrity::Invalid(reason) => write!(f, "invalid: {}", reason),
+        }
+    }
+}
+
+/// A cached input along with what is known about it.
+#[derive(Clone, Debug)]
+pub struct Entry {
+    pub year: i32,
+    pub day: i32,
+    /// The location of the input.
+    pub path: PathBuf,
+    /// The metadata of the input, `None` if it was cached before metadata was recorded.
+    pub metadata: Option<Metadata>,
+    pub integrity: Integrity,
+}
+
+/// Lists the inputs cached for the profile, ordered by year and day.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Entry>)` - The cached inputs, checked against their metadata.
+/// * `Err(AocError)` - If the cache cannot be read.
+pub fn entries(context: &Context) -> Result<Vec<Entry>, AocError> {
+    let dir = cache_dir(context)?;
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut puzzles = vec![];
+    for file in fs::read_dir(&dir)? {
+        let name = file?.file_name();
+        if let Some(puzzle) = name.to_str().and_then(parse_file_name) {
+            puzzles.push(puzzle);
+        }
+    }
+    puzzles.sort();
+
+    puzzles.into_iter().filter_map(|(year, day)| entry(&year, &day, context).transpose()).collect()
+}
+
+/// Looks up a cached input and checks it against its metadata.
+///
+/// # Returns
+///
+/// * `Ok(Some(Entry))` - If the input is cached.
+/// * `Ok(None)` - If the input is not cached.
+/// * `Err(AocError)` - If the input or its metadata cannot be read.
+pub fn entry(year: &i32, day: &i32, context: &Context) -> Result<Option<Entry>, AocError> {
+    Ok(read(year, day, context)?.map(|(_, entry)| entry))
+}
+
+/// Removes a cached input along with its metadata.
+///
+/// # Returns
+///
+/// * `Ok(true)` - If the input was cached and has been removed.
+/// * `Ok(false)` - If the input was not cached.
+/// * `Err(AocError)` - If the input or its metadata cannot be removed.
+pub fn purge(year: &i32, day: &i32, context: &Context) -> Result<bool, AocError> {
+    let dir = cache_dir(context)?;
+    let removed = remove(dir.join(build_file_name(year, day)))?;
+    remove(dir.join(metadata_file_name(year, day)))?;
+    Ok(removed)
+}
+
+/// Reads a cached input, if it exists, along with its metadata checked against it.
+pub(crate) fn read(year: &i32, day: &i32, context: &Context) -> Result<Option<(String, Entry)>, AocError> {
+    let dir = cache_dir(context)?;
+    let path = dir.join(build_file_name(year, day));
+    if !path.exists() {
+        return Ok(None);
+    }
+
+    let body = fs::read_to_string(&path)?;
+    let metadata_file = dir.join(metadata_file_name(year, day));
+    let (metadata, integrity) = if metadata_file.exists() {
+        match Metadata::parse(&fs::read_to_string(&metadata_file)?) {
+            Ok(metadata) => {
+                let integrity = check(&body, Some(&metadata), context);
+                (Some(metadata), integrity)
+            }
+            Err(e) => (None, Integrity::Invalid(format!("unreadable metadata, {}", e.to_lowercase()))),
+        }
+    } else {
+        (None, check(&body, None, context))
+    };
+
+    let entry = Entry {
+        year: *year,
+        day: *day,
+        path,
+        metadata,
+        integrity,
+    };
+    Ok(Some((body, entry)))
+}
+
+/// Writes an input and its metadata to the cache.
+pub(crate) fn write(year: &i32, day: &i32, body: &str, metadata: &Metadata, context: &Context) -> Result<(), AocError> {
+    let dir = cache_dir(context)?;
+    if !dir.exists() {
+        fs::create_dir_all(&dir)?;
+    }
+
+    fs::write(dir.join(build_file_name(year, day)), body)?;
+    Ok(fs::write(dir.join(metadata_file_name(year, day)), metadata.to_record())?)
+}
+
+/// Determines whether a cached input can be used.
+///
+/// # Arguments
+///
+/// * `body` - The cached input.
+/// * `metadata` - The metadata of the input, if any was recorded.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+pub fn check(body: &str, metadata: Option<&Metadata>, context: &Context) -> Integrity {
+    // inputs cached before metadata was recorded may hold the page served to logged out visitors
+    if session::is_logged_out(body) {
+        return Integrity::Invalid("the site refused the session".to_string());
+    }
+
+    let Some(metadata) = metadata else {
+        return Integrity::Unverified;
+    };
+
+    if !(200..300).contains(&metadata.status) {
+        Integrity::Invalid(format!("cached from a {} response", metadata.status))
+    } else if metadata.profile != context.profile {
+        Integrity::Invalid(format!("fetched for the {} profile", metadata.profile.as_deref().unwrap_or("default")))
+    } else if metadata.length != body.len() as u64 {
+        Integrity::Invalid(format!("expected {} bytes, found {}", metadata.length, body.len()))
+    } else if metadata.sha256 != digest(body) {
+        Integrity::Invalid("the SHA-256 digest does not match".to_string())
+    } else {
+        Integrity::Valid
+    }
+}
+
+/// Formats when an input was fetched.
+pub fn format_fetched_at(metadata: &Metadata) -> String {
+    unlock::format_utc(metadata.fetched_at)
+}
+
+fn digest(body: &str) -> String {
+    Sha256::digest(body.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+pub(crate) fn cache_dir(context: &Context) -> Result<PathBuf, AocError> {
+    Ok(std::env::current_dir()?.join(context.profile_dir()))
+}
+
+fn metadata_file_name(year: &i32, day: &i32) -> String {
+    format!("{}.day{}.meta", year, day)
+}
+
+fn parse_file_name(name: &str) -> Option<(i32, i32)> {
+    let (year, day) = name.strip_suffix(".dat")?.split_once(".day")?;
+    Some((year.parse().ok()?, day.parse().ok()?))
+}
+
+fn remove(path: PathBuf) -> Result<bool, AocError> {
+    match fs::remove_file(path) {
+        Ok(()) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_context;
+    use crate::unlock::FixedClock;
+    use std::sync::Arc;
+
+    fn get_context(name: &str) -> Context {
+        let context = Context {
+            clock: Arc::new(FixedClock(UNIX_EPOCH + Duration::from_secs(1733029200))),
+            ..test_context(&format!("aoc_cache_test/{}", name), "https://adventofcode.com")
+        };
+        let _ = fs::remove_dir_all(&context.data_dir);
+        context
+    }
+
+    #[test]
+    fn metadata_round_trips() {
+        let context = Context {
+            profile: Some("alice".to_string()),
+            ..get_context("round_trip")
+        };
+        let metadata = Metadata::new("1 2\n3 4\n", 200, &context);
+
+        assert_eq!(metadata.length, 8);
+        assert_eq!(metadata.sha256.len(), 64);
+        assert_eq!(format_fetched_at(&metadata), "2024-12-01 05:00:00 UTC");
+        assert_eq!(Metadata::parse(&metadata.to_record()), Ok(metadata));
+        assert!(Metadata::parse("length\t8\n").is_err());
+    }
+
+    #[test]
+    fn entries_are_checked() {
+        let context = get_context("checked");
+        let metadata = Metadata::new("1 2\n3 4\n", 200, &context);
+        write(&1013, &1, "1 2\n3 4\n", &metadata, &context).unwrap();
+        write(&1013, &2, "1 2\n3 4\n", &metadata, &context).unwrap();
+        write(&1012, &3, "1 2\n3 4\n", &Metadata { status: 500, ..metadata.clone() }, &context).unwrap();
+        // tampered with after it was cached
+        fs::write(cache_dir(&context).unwrap().join("1013.day2.dat"), "1 2\n3 5\n").unwrap();
+        // cached before metadata was recorded
+        fs::write(cache_dir(&context).unwrap().join("1013.day4.dat"), "5 6\n").unwrap();
+
+        let entries = entries(&context).unwrap();
+        let summary: Vec<_> = entries.iter().map(|e| (e.year, e.day, e.integrity.clone())).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1012, 3, Integrity::Invalid("cached from a 500 response".to_string())),
+                (1013, 1, Integrity::Valid),
+                (1013, 2, Integrity::Invalid("the SHA-256 digest does not match".to_string())),
+                (1013, 4, Integrity::Unverified),
+            ]
+        );
+    }
+
+    #[test]
+    fn logged_out_pages_are_invalid() {
+        let context = get_context("logged_out");
+        let body = "Puzzle inputs differ by user.  Please log in to get your puzzle input.";
+        assert!(matches!(check(body, None, &context), Integrity::Invalid(_)));
+    }
+
+    #[test]
+    fn entries_are_purged() {
+        let context = get_context("purged");
+        write(&1013, &1, "1 2\n", &Metadata::new("1 2\n", 200, &context), &context).unwrap();
+
+        assert!(purge(&1013, &1, &context).unwrap());
+        assert!(!purge(&1013, &1, &context).unwrap());
+        assert!(entry(&1013, &1, &context).unwrap().is_none());
+        assert!(!cache_dir(&context).unwrap().join("1013.day1.meta").exists());
+    }
+
+    #[test]
+    fn unreadable_metadata_is_invalid() {
+        let context = get_context("unreadable");
+        write(&1013, &1, "1 2\n", &Metadata::new("1 2\n", 200, &context), &context).unwrap();
+        fs::write(cache_dir(&context).unwrap().join("1013.day1.meta"), "length\tmany\n").unwrap();
+
+        let entry = entry(&1013, &1, &context).unwrap().unwrap();
+        assert_eq!(entry.metadata, None);
+        assert_eq!(entry.integrity, Integrity::Invalid("unreadable metadata, missing fetched_at".to_string()));
+    }
+}
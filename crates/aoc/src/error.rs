@@ -0,0 +1,109 @@
+use crate::session::SessionError;
+use crate::unlock;
+use std::fmt;
+use std::io;
+use std::time::{Duration, SystemTime};
+
+/// The first year Advent of Code was held.
+pub const FIRST_YEAR: i32 = 2015;
+
+/// The ways interacting with the cache or the site can fail.
+#[derive(Debug)]
+pub enum AocError {
+    /// Reading or writing a local file failed.
+    Io(io::Error),
+    /// The site answered with an unexpected status.
+    Status { url: String, status: u16, body: String },
+    /// The site could not be reached or the response could not be read.
+    Network(String),
+    /// The site did not accept the session.
+    Auth(SessionError),
+    /// The site asked for fewer requests, optionally saying how long to wait.
+    RateLimited { retry_after: Option<Duration> },
+    /// The puzzle has not been released yet.
+    NotYetUnlocked { opens_at: SystemTime },
+    /// The input is not cached and there is no session to download it with.
+    MissingInput { year: i32, day: i32 },
+    /// The year or day is outside of the event.
+    InvalidPuzzle { year: i32, day: i32 },
+    /// A part without an answer was about to be submitted.
+    Unsolved { part: i32 },
+    /// The site answered in a way that was not understood.
+    UnexpectedResponse(String),
+    /// The address of the site cannot have pages beneath it.
+    InvalidUrl(String),
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "{}", e),
+            AocError::Status { url, status, body } => write!(f, "{} responded with {}: {}", url, status, body.trim()),
+            AocError::Network(e) => write!(f, "{}", e),
+            AocError::Auth(e) => write!(f, "{}", e),
+            AocError::RateLimited { retry_after: Some(wait) } => write!(f, "Rate limited, wait {}s before trying again", wait.as_secs()),
+            AocError::RateLimited { retry_after: None } => write!(f, "Rate limited, wait before trying again"),
+            AocError::NotYetUnlocked { opens_at } => write!(f, "The puzzle unlocks at {}", unlock::format_utc(*opens_at)),
+            AocError::MissingInput { year, day } => write!(f, "The input for {} Day {} is not cached and cannot be downloaded without a session", year, day),
+            AocError::InvalidPuzzle { year, day } => write!(f, "{} Day {} is not a puzzle, expected a year from {} and a day from 1 to 25", year, day, FIRST_YEAR),
+            AocError::Unsolved { part } => write!(f, "Part {} is unsolved, there is nothing to submit", part),
+            AocError::UnexpectedResponse(body) => write!(f, "Unrecognized response: {}", body),
+            AocError::InvalidUrl(url) => write!(f, "{} cannot be used as the address of the site", url),
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
+
+impl From<reqwest::Error> for AocError {
+    fn from(e: reqwest::Error) -> Self {
+        AocError::Network(e.to_string())
+    }
+}
+
+impl From<SessionError> for AocError {
+    fn from(e: SessionError) -> Self {
+        AocError::Auth(e)
+    }
+}
+
+/// Ensures a year and day identify a puzzle of the event.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the year is not before the first event and the day is from 1 to 25.
+/// * `Err(AocError::InvalidPuzzle)` - Otherwise.
+pub fn check_puzzle(year: &i32, day: &i32) -> Result<(), AocError> {
+    if *year >= FIRST_YEAR && (1..=25).contains(day) {
+        Ok(())
+    } else {
+        Err(AocError::InvalidPuzzle { year: *year, day: *day })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn puzzles_are_checked() {
+        assert!(check_puzzle(&2015, &1).is_ok());
+        assert!(check_puzzle(&2024, &25).is_ok());
+        assert!(matches!(check_puzzle(&2014, &1), Err(AocError::InvalidPuzzle { year: 2014, day: 1 })));
+        assert!(matches!(check_puzzle(&2024, &0), Err(AocError::InvalidPuzzle { .. })));
+        assert!(matches!(check_puzzle(&2024, &26), Err(AocError::InvalidPuzzle { .. })));
+    }
+}
@@ -0,0 +1,287 @@
+use crate::AocError;
+use reqwest::{Client, RequestBuilder, Response};
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+/// Identifies the tool to the site operators, who ask automated tools to say how to contact their owner.
+pub const DEFAULT_USER_AGENT: &str = "github.com/Tedford/advent-of-code-2024";
+
+/// Settings for the client shared by every request to the site.
+#[derive(Clone, Debug)]
+pub struct HttpConfig {
+    /// The User-Agent sent with every request, ideally including a way to contact the owner.
+    pub user_agent: String,
+    /// The shortest time allowed between the start of two requests.
+    pub min_interval: Duration,
+    /// Where the time of the last request is kept so the interval also holds across processes.
+    pub state_file: Option<PathBuf>,
+    /// How many times a GET request is repeated after a server error or timeout.
+    pub max_retries: u32,
+    /// The wait before the first retry, doubling with every further retry.
+    pub backoff: Duration,
+    /// How long a single request may take.
+    pub timeout: Duration,
+}
+
+impl Default for HttpConfig {
+    fn default() -> Self {
+        HttpConfig {
+            user_agent: DEFAULT_USER_AGENT.to_string(),
+            min_interval: Duration::from_secs(3),
+            state_file: None,
+            max_retries: 3,
+            backoff: Duration::from_millis(500),
+            timeout: Duration::from_secs(30),
+        }
+    }
+}
+
+/// A throttled client which retries transient failures, shared by every request to the site.
+pub struct HttpClient {
+    client: Client,
+    config: HttpConfig,
+    last_request: Mutex<Option<SystemTime>>,
+}
+
+impl HttpClient {
+    /// Builds a client from its settings.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(HttpClient)` - If the client is built successfully.
+    /// * `Err(AocError)` - If the underlying HTTP client cannot be built.
+    pub fn new(config: HttpConfig) -> Result<HttpClient, AocError> {
+        let client = Client::builder().user_agent(&config.user_agent).timeout(config.timeout).build()?;
+        Ok(HttpClient {
+            client,
+            config,
+            last_request: Mutex::new(None),
+        })
+    }
+
+    /// The settings the client was built with.
+    pub fn config(&self) -> &HttpConfig {
+        &self.config
+    }
+
+    /// Sends a GET request presenting the session cookie.
+    pub async fn get(&self, url: &str, session_id: &str) -> Result<Response, AocError> {
+        self.send(|| with_session(self.client.get(url), session_id), self.config.max_retries).await
+    }
+
+    /// Sends a form as a POST request presenting the session cookie.
+    ///
+    /// The request is never repeated: a failed submission may still have been counted by the site,
+    /// and submitting it again could count as another wrong answer.
+    pub async fn post_form(&self, url: &str, session_id: &str, form: &[(&str, String)]) -> Result<Response, AocError> {
+        self.send(|| with_session(self.client.post(url), session_id).form(form), 0).await
+    }
+
+    /// Sends a request once the minimum interval has passed, retrying server errors and timeouts.
+    ///
+    /// The response to the last attempt is returned even if it is a server error so the caller can
+    /// report what the site said.
+    async fn send(&self, build: impl Fn() -> RequestBuilder, max_retries: u32) -> Result<Response, AocError> {
+        let mut attempt = 0;
+        loop {
+            self.throttle().await;
+
+            let retryable = match build().send().await {
+                Ok(response) if response.status().is_server_error() && attempt < max_retries => true,
+                Ok(response) => return Ok(response),
+                Err(e) if (e.is_timeout() || e.is_connect()) && attempt < max_retries => true,
+                Err(e) => return Err(e.into()),
+            };
+
+            if retryable {
+                tokio::time::sleep(self.config.backoff * 2u32.pow(attempt)).await;
+                attempt += 1;
+            }
+        }
+    }
+
+    /// Waits until the minimum interval since the last request by any process has passed, then
+    /// records the start of a new request.
+    ///
+    /// The lock is held while waiting so concurrent requests from this process take turns rather
+    /// than all waking at the end of the same interval.
+    async fn throttle(&self) {
+        let mut last_request = self.last_request.lock().await;
+        let last = [*last_request, self.read_state()].into_iter().flatten().max();
+        if let Some(wait) = last.and_then(|last| (last + self.config.min_interval).duration_since(SystemTime::now()).ok()) {
+            tokio::time::sleep(wait).await;
+        }
+
+        let now = SystemTime::now();
+        *last_request = Some(now);
+        self.write_state(now);
+    }
+
+    fn read_state(&self) -> Option<SystemTime> {
+        let millis = fs::read_to_string(self.config.state_file.as_ref()?).ok()?.trim().parse::<u64>().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    fn write_state(&self, time: SystemTime) {
+        let (Some(path), Ok(since_epoch)) = (&self.config.state_file, time.duration_since(UNIX_EPOCH)) else {
+            return;
+        };
+
+        // the interval is a courtesy, failing to persist it must not stop the request
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent);
+        }
+        let _ = fs::write(path, since_epoch.as_millis().to_string());
+    }
+}
+
+impl Default for HttpClient {
+    /// Builds a client with the default settings.
+    ///
+    /// # Panics
+    ///
+    /// Like `reqwest::Client::new`, if the TLS backend cannot be initialized.
+    fn default() -> Self {
+        HttpClient::new(HttpConfig::default()).expect("Unable to build the HTTP client")
+    }
+}
+
+fn with_session(request: RequestBuilder, session_id: &str) -> RequestBuilder {
+    if session_id.is_empty() {
+        request
+    } else {
+        request.header(reqwest::header::COOKIE, format!("session={}", session_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Instant;
+
+    fn client(config: HttpConfig) -> HttpClient {
+        HttpClient::new(HttpConfig {
+            backoff: Duration::from_millis(1),
+            ..config
+        })
+        .unwrap()
+    }
+
+    // this is not using tokio::test due to runtime block_on collisions when using mockito
+    #[test]
+    fn user_agent_and_session_are_sent() {
+        let mut server = mockito::Server::new();
+        let mock = server
+            .mock("GET", "/page")
+            .match_header("user-agent", "tests by someone@example.com")
+            .match_header("cookie", "session=fake_session_id")
+            .with_status(200)
+            .create();
+
+        let client = client(HttpConfig {
+            user_agent: "tests by someone@example.com".to_string(),
+            ..HttpConfig::default()
+        });
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let response = runtime.block_on(client.get(&format!("{}/page", server.url()), "fake_session_id")).unwrap();
+
+        assert_eq!(response.status(), 200);
+        mock.assert();
+    }
+
+    #[test]
+    fn server_errors_are_retried() {
+        let mut server = mockito::Server::new();
+        let failure = server.mock("GET", "/page").with_status(502).expect(3).create();
+
+        let config = HttpConfig {
+            min_interval: Duration::ZERO,
+            max_retries: 2,
+            ..HttpConfig::default()
+        };
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let response = runtime.block_on(client(config.clone()).get(&format!("{}/page", server.url()), "")).unwrap();
+
+        // the last attempt is reported once the retries are exhausted
+        assert_eq!(response.status(), 502);
+        failure.assert();
+
+        let failure = server.mock("GET", "/recover").with_status(500).expect(1).create();
+        let success = server.mock("GET", "/recover").with_status(200).expect(1).create();
+        let response = runtime.block_on(client(config).get(&format!("{}/recover", server.url()), "")).unwrap();
+
+        assert_eq!(response.status(), 200);
+        failure.assert();
+        success.assert();
+    }
+
+    #[test]
+    fn posts_are_not_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/answer").with_status(502).expect(1).create();
+
+        let config = HttpConfig {
+            min_interval: Duration::ZERO,
+            ..HttpConfig::default()
+        };
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let form = [("level", "1".to_string()), ("answer", "42".to_string())];
+        let response = runtime.block_on(client(config).post_form(&format!("{}/answer", server.url()), "", &form)).unwrap();
+
+        assert_eq!(response.status(), 502);
+        mock.assert();
+    }
+
+    #[test]
+    fn client_errors_are_not_retried() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/page").with_status(404).expect(1).create();
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let response = runtime.block_on(client(HttpConfig::default()).get(&format!("{}/page", server.url()), "")).unwrap();
+
+        assert_eq!(response.status(), 404);
+        mock.assert();
+    }
+
+    #[test]
+    fn interval_is_kept_across_clients() {
+        let mut server = mockito::Server::new();
+        let _m = server.mock("GET", "/page").with_status(200).expect(2).create();
+
+        let state_file = std::env::temp_dir().join("aoc_http_test").join("last_request");
+        let _ = fs::remove_file(&state_file);
+        let config = HttpConfig {
+            min_interval: Duration::from_millis(300),
+            state_file: Some(state_file),
+            ..HttpConfig::default()
+        };
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let url = format!("{}/page", server.url());
+        let start_time = Instant::now();
+        runtime.block_on(client(config.clone()).get(&url, "")).unwrap();
+        // a second client stands in for another process sharing the state file
+        runtime.block_on(client(config).get(&url, "")).unwrap();
+
+        assert!(start_time.elapsed() >= Duration::from_millis(300));
+    }
+
+    #[test]
+    fn concurrent_requests_take_turns() {
+        let config = HttpConfig {
+            min_interval: Duration::from_millis(200),
+            ..HttpConfig::default()
+        };
+        let client = client(config);
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let start_time = Instant::now();
+        runtime.block_on(async { tokio::join!(client.throttle(), client.throttle(), client.throttle()) });
+
+        // the first request goes straight away and each of the others waits a full interval
+        assert!(start_time.elapsed() >= Duration::from_millis(400));
+    }
+}
@@ -0,0 +1,160 @@
+/// The puzzle input exactly as it was served by the site.
+///
+/// The text is kept verbatim, including leading whitespace and trailing blank lines, so inputs
+/// where spacing matters survive the trip through the cache. The accessors provide the common
+/// views of it so a parser can be composed from them without each day splitting the text by hand.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Input {
+    raw: String,
+}
+
+impl Input {
+    pub fn new(raw: impl Into<String>) -> Input {
+        Input { raw: raw.into() }
+    }
+
+    /// The text of the input as it was served.
+    pub fn raw(&self) -> &str {
+        &self.raw
+    }
+
+    /// The lines of the input without their `\n` or `\r\n` endings.
+    ///
+    /// A final line ending does not start another line, so `"a\nb\n"` has two lines.
+    pub fn lines(&self) -> Vec<&str> {
+        self.raw.lines().collect()
+    }
+
+    /// The first line of the input, empty if there is none.
+    pub fn first_line(&self) -> &str {
+        self.raw.lines().next().unwrap_or("")
+    }
+
+    /// The blocks of the input separated by blank lines, each usable as an input of its own.
+    ///
+    /// Runs of blank lines count as a single separator and blank lines at the start or end of
+    /// the input do not produce empty sections.
+    pub fn sections(&self) -> Vec<Input> {
+        let mut sections = vec![];
+        let mut current: Vec<&str> = vec![];
+        for line in self.raw.lines() {
+            if line.trim().is_empty() {
+                if !current.is_empty() {
+                    sections.push(Input::new(std::mem::take(&mut current).join("\n")));
+                }
+            } else {
+                current.push(line);
+            }
+        }
+        if !current.is_empty() {
+            sections.push(Input::new(current.join("\n")));
+        }
+        sections
+    }
+
+    /// Every signed integer in the input in the order they appear, ignoring the text around them.
+    ///
+    /// A `-` is only read as a sign when it does not follow a digit, so ranges such as `3-5`
+    /// give `3` and `5`.
+    ///
+    /// The integers are as wide as a [`crate::Answer::Number`] so that long runs of digits can be read.
+    /// A run which does not fit in an `i128`, such as a dense map of single digits, is not an integer
+    /// and is skipped; read those inputs with [`Input::raw`] instead.
+    pub fn ints(&self) -> Vec<i128> {
+        let mut result = vec![];
+        let mut chars = self.raw.char_indices().peekable();
+        let mut after_digit = false;
+        while let Some((start, c)) = chars.next() {
+            let sign = c == '-' && !after_digit && chars.peek().is_some_and(|(_, next)| next.is_ascii_digit());
+            if !c.is_ascii_digit() && !sign {
+                after_digit = false;
+                continue;
+            }
+
+            let mut end = start + 1;
+            while let Some((i, _)) = chars.next_if(|(_, next)| next.is_ascii_digit()) {
+                end = i + 1;
+            }
+            if let Ok(value) = self.raw[start..end].parse() {
+                result.push(value);
+            }
+            after_digit = true;
+        }
+        result
+    }
+
+    /// The characters of the input as rows, ignoring blank lines at the end of the input.
+    pub fn grid(&self) -> Vec<Vec<char>> {
+        let mut lines = self.lines();
+        while lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        lines.iter().map(|line| line.chars().collect()).collect()
+    }
+}
+
+impl From<&str> for Input {
+    fn from(raw: &str) -> Self {
+        Input::new(raw)
+    }
+}
+
+impl From<String> for Input {
+    fn from(raw: String) -> Self {
+        Input::new(raw)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn raw_text_is_preserved() {
+        let input = Input::from("  leading\n\ntrailing\n\n");
+        assert_eq!(input.raw(), "  leading\n\ntrailing\n\n");
+        assert_eq!(input.lines(), vec!["  leading", "", "trailing", ""]);
+    }
+
+    #[test]
+    fn line_endings_are_removed() {
+        assert_eq!(Input::from("a\r\nb\r\n").lines(), vec!["a", "b"]);
+        assert_eq!(Input::from("a\nb").lines(), vec!["a", "b"]);
+        assert!(Input::from("").lines().is_empty());
+    }
+
+    #[test]
+    fn sections_are_split_on_blank_lines() {
+        let input = Input::from("\r\n47|53\r\n97|13\r\n\r\n\r\n75,47\r\n");
+        let sections = input.sections();
+        assert_eq!(sections, vec![Input::from("47|53\n97|13"), Input::from("75,47")]);
+        assert_eq!(sections[0].lines(), vec!["47|53", "97|13"]);
+        assert_eq!(sections[1].first_line(), "75,47");
+    }
+
+    #[test]
+    fn first_line_of_empty_input() {
+        assert_eq!(Input::from("").first_line(), "");
+        assert_eq!(Input::from("r, wr\n\nbrwrr").first_line(), "r, wr");
+    }
+
+    #[test]
+    fn signed_integers_are_found() {
+        assert_eq!(Input::from("Button A: X+94, Y+34\nPrize: X=-8400, Y=5400").ints(), vec![94, 34, -8400, 5400]);
+        assert_eq!(Input::from("2-4,6-8\n-1 -x 3").ints(), vec![2, 4, 6, 8, -1, 3]);
+        assert!(Input::from("no numbers -").ints().is_empty());
+        assert_eq!(Input::from("12345678901234567890123").ints(), vec![12345678901234567890123]);
+    }
+
+    #[test]
+    fn ints_skip_runs_too_long_for_an_i128() {
+        let input = Input::from("7 1234567890123456789012345678901234567890 -8");
+        assert_eq!(input.ints(), vec![7, -8]);
+    }
+
+    #[test]
+    fn grid_ignores_trailing_blank_lines() {
+        let input = Input::from("#.\r\n.#\n\n");
+        assert_eq!(input.grid(), vec![vec!['#', '.'], vec!['.', '#']]);
+    }
+}
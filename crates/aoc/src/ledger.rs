@@ -0,0 +1,357 @@
+use crate::{Answer, AocError, Context, Verdict};
+use std::cmp::Ordering;
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+/// An answer which has been submitted along with the verdict it received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub part: i32,
+    pub answer: Answer,
+    pub verdict: Verdict,
+}
+
+/// A persistent record of every answer submitted for a puzzle.
+///
+/// The ledger is stored as `{year}.day{day}.answers` in the data directory with one tab separated
+/// `part verdict answer` line per submission. Answers which are expected without ever having been
+/// submitted are kept apart from the verdicts of the site on `part expected answer` lines.
+///
+/// Each answer starts with its kind, `n:` for a number, `t:` for text and `u:` for an unsolved part,
+/// so text which looks like a number is read back as text. Backslashes, tabs and line breaks in an
+/// answer are escaped so every entry stays on its own line.
+pub struct Ledger {
+    path: PathBuf,
+    entries: Vec<Entry>,
+    expected: Vec<(i32, Answer)>,
+}
+
+impl Ledger {
+    /// Loads the ledger for the specified year and day, starting an empty one if none exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of the event.
+    /// * `day` - The day of the event.
+    /// * `context` - Defines the context to interact with the Advent of Code website.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Ledger)` - If the ledger is loaded successfully.
+    /// * `Err(AocError)` - If the ledger exists but cannot be read or parsed.
+    pub fn load(year: &i32, day: &i32, context: &Context) -> Result<Ledger, AocError> {
+        let path = context.profile_dir().join(format!("{}.day{}.answers", year, day));
+        let mut ledger = Ledger {
+            path,
+            entries: vec![],
+            expected: vec![],
+        };
+        if !ledger.path.exists() {
+            return Ok(ledger);
+        }
+
+        for line in fs::read_to_string(&ledger.path)?.lines().filter(|line| !line.is_empty()) {
+            match parse_line(line) {
+                Ok((part, Some(verdict), answer)) => ledger.entries.push(Entry { part, answer, verdict }),
+                Ok((part, None, answer)) => ledger.expected.push((part, answer)),
+                Err(e) => {
+                    let message = format!("{}: {}", ledger.path.display(), e);
+                    return Err(io::Error::new(io::ErrorKind::InvalidData, message).into());
+                }
+            }
+        }
+
+        Ok(ledger)
+    }
+
+    /// The answers recorded so far in the order they were submitted.
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// The accepted answer to a part, if one has been recorded.
+    pub fn correct(&self, part: i32) -> Option<&Answer> {
+        self.entries.iter().find(|e| e.part == part && e.verdict == Verdict::Correct).map(|e| &e.answer)
+    }
+
+    /// The answer a part is expected to have, being the accepted answer or else the answer recorded
+    /// as expected without being submitted.
+    pub fn expected(&self, part: i32) -> Option<&Answer> {
+        self.correct(part).or_else(|| self.expected.iter().find(|(p, _)| *p == part).map(|(_, answer)| answer))
+    }
+
+    /// Determines if an answer is worth submitting given what has been learned from earlier attempts.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the answer has not been ruled out.
+    /// * `Err(String)` - The reason the answer is known to be wrong.
+    pub fn check(&self, part: i32, answer: &Answer) -> Result<(), String> {
+        if let Some(correct) = self.correct(part) {
+            return Err(if correct == answer {
+                format!("Part {} has already been solved with {}", part, correct)
+            } else {
+                format!("Part {} was solved with {}, not {}", part, correct, answer)
+            });
+        }
+
+        for entry in self.entries.iter().filter(|e| e.part == part) {
+            if &entry.answer == answer {
+                return Err(format!("{} was already submitted for part {}: {}", answer, part, entry.verdict));
+            }
+
+            match (&entry.verdict, answer.partial_cmp(&entry.answer)) {
+                (Verdict::TooHigh, Some(Ordering::Greater)) => {
+                    return Err(format!("{} is above {} which is known to be too high", answer, entry.answer));
+                }
+                (Verdict::TooLow, Some(Ordering::Less)) => {
+                    return Err(format!("{} is below {} which is known to be too low", answer, entry.answer));
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records the verdict for a submitted answer.
+    ///
+    /// Verdicts that say nothing about the answer, such as being rate limited, are not recorded.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the verdict is recorded or ignored.
+    /// * `Err(AocError)` - If there is an error writing the ledger.
+    pub fn record(&mut self, part: i32, answer: &Answer, verdict: &Verdict) -> Result<(), AocError> {
+        if matches!(verdict, Verdict::RateLimited(_) | Verdict::AlreadySolved) {
+            return Ok(());
+        }
+
+        self.entries.push(Entry {
+            part,
+            answer: answer.clone(),
+            verdict: verdict.clone(),
+        });
+        self.save()
+    }
+
+    /// Records the answer a part is expected to have without it having been submitted.
+    ///
+    /// Expected answers are only used to verify solutions, they are never taken as verdicts of the
+    /// site when deciding whether an answer is worth submitting.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the answer is recorded.
+    /// * `Err(AocError)` - If there is an error writing the ledger.
+    pub fn expect(&mut self, part: i32, answer: &Answer) -> Result<(), AocError> {
+        self.expected.retain(|(p, _)| *p != part);
+        self.expected.push((part, answer.clone()));
+        self.save()
+    }
+
+    fn save(&self) -> Result<(), AocError> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let entries = self.entries.iter().map(|e| (e.part, verdict_key(&e.verdict), &e.answer));
+        let expected = self.expected.iter().map(|(part, answer)| (*part, "expected", answer));
+        let body = entries
+            .chain(expected)
+            .map(|(part, key, answer)| format!("{}\t{}\t{}\n", part, key, encode(answer)))
+            .collect::<String>();
+        Ok(fs::write(&self.path, body)?)
+    }
+}
+
+fn verdict_key(verdict: &Verdict) -> &'static str {
+    match verdict {
+        Verdict::Correct => "correct",
+        Verdict::TooHigh => "too-high",
+        Verdict::TooLow => "too-low",
+        _ => "wrong",
+    }
+}
+
+/// Parses a line of the ledger, where an answer without a verdict is one which is expected.
+fn parse_line(line: &str) -> Result<(i32, Option<Verdict>, Answer), String> {
+    let [part, verdict, answer] = line.splitn(3, '\t').collect::<Vec<_>>()[..] else {
+        return Err(format!("Unexpected ledger entry {}", line));
+    };
+
+    let part = part.parse::<i32>().map_err(|e| e.to_string())?;
+    let verdict = match verdict {
+        "correct" => Some(Verdict::Correct),
+        "too-high" => Some(Verdict::TooHigh),
+        "too-low" => Some(Verdict::TooLow),
+        "wrong" => Some(Verdict::Wrong),
+        "expected" => None,
+        x => return Err(format!("Unexpected verdict {}", x)),
+    };
+    let answer = decode(answer)?;
+
+    Ok((part, verdict, answer))
+}
+
+fn encode(answer: &Answer) -> String {
+    match answer {
+        Answer::Number(x) => format!("n:{}", x),
+        Answer::Text(x) => format!("t:{}", escape(x)),
+        Answer::Unsolved => "u:".to_string(),
+    }
+}
+
+fn decode(answer: &str) -> Result<Answer, String> {
+    match answer.split_once(':') {
+        Some(("n", x)) => x.parse::<i128>().map(Answer::Number).map_err(|e| format!("Unexpected number {}: {}", x, e)),
+        Some(("t", x)) => unescape(x).map(Answer::Text),
+        Some(("u", "")) => Ok(Answer::Unsolved),
+        _ => Err(format!("Unexpected answer {}", answer)),
+    }
+}
+
+fn escape(answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\t', "\\t").replace('\n', "\\n").replace('\r', "\\r")
+}
+
+fn unescape(answer: &str) -> Result<String, String> {
+    let mut unescaped = String::with_capacity(answer.len());
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => unescaped.push('\\'),
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            x => return Err(format!("Unexpected escape {:?} in answer {}", x, answer)),
+        }
+    }
+    Ok(unescaped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_context;
+
+    fn get_context() -> Context {
+        test_context("aoc_ledger_test", "https://adventofcode.com")
+    }
+
+    fn get_ledger(day: i32) -> Ledger {
+        let context = get_context();
+        let _ = fs::remove_file(context.data_dir.join(format!("1013.day{}.answers", day)));
+        Ledger::load(&1013, &day, &context).unwrap()
+    }
+
+    #[test]
+    fn entries_are_persisted() {
+        let mut ledger = get_ledger(1);
+        ledger.record(1, &Answer::from(10), &Verdict::TooLow).unwrap();
+        ledger.record(1, &Answer::from("6,1"), &Verdict::Wrong).unwrap();
+        ledger.record(2, &Answer::from(12), &Verdict::Correct).unwrap();
+
+        let reloaded = Ledger::load(&1013, &1, &get_context()).unwrap();
+        assert_eq!(reloaded.entries(), ledger.entries());
+        assert_eq!(reloaded.correct(2), Some(&Answer::from(12)));
+        assert_eq!(reloaded.correct(1), None);
+    }
+
+    #[test]
+    fn uninformative_verdicts_are_ignored() {
+        let mut ledger = get_ledger(2);
+        ledger.record(1, &Answer::from(10), &Verdict::RateLimited(std::time::Duration::from_secs(5))).unwrap();
+        ledger.record(1, &Answer::from(10), &Verdict::AlreadySolved).unwrap();
+        assert!(ledger.entries().is_empty());
+    }
+
+    #[test]
+    fn known_wrong_answer_is_refused() {
+        let mut ledger = get_ledger(3);
+        ledger.record(1, &Answer::from(10), &Verdict::Wrong).unwrap();
+
+        assert!(ledger.check(1, &Answer::from(10)).is_err());
+        assert!(ledger.check(1, &Answer::from(11)).is_ok());
+        assert!(ledger.check(2, &Answer::from(10)).is_ok());
+    }
+
+    #[test]
+    fn answers_outside_bounds_are_refused() {
+        let mut ledger = get_ledger(4);
+        ledger.record(1, &Answer::from(10), &Verdict::TooLow).unwrap();
+        ledger.record(1, &Answer::from(20), &Verdict::TooHigh).unwrap();
+
+        assert!(ledger.check(1, &Answer::from(5)).is_err());
+        assert!(ledger.check(1, &Answer::from(25)).is_err());
+        assert!(ledger.check(1, &Answer::from(15)).is_ok());
+    }
+
+    #[test]
+    fn solved_part_is_refused() {
+        let mut ledger = get_ledger(5);
+        ledger.record(1, &Answer::from(15), &Verdict::Correct).unwrap();
+
+        assert!(ledger.check(1, &Answer::from(15)).is_err());
+        assert!(ledger.check(1, &Answer::from(16)).is_err());
+    }
+
+    #[test]
+    fn expected_answers_are_not_verdicts() {
+        let mut ledger = get_ledger(7);
+        ledger.expect(1, &Answer::from(15)).unwrap();
+        ledger.record(1, &Answer::from(10), &Verdict::TooLow).unwrap();
+
+        let reloaded = Ledger::load(&1013, &7, &get_context()).unwrap();
+        assert_eq!(reloaded.entries(), ledger.entries());
+        assert_eq!(reloaded.expected(1), Some(&Answer::from(15)));
+        assert_eq!(reloaded.correct(1), None);
+        assert!(reloaded.check(1, &Answer::from(15)).is_ok());
+        assert!(reloaded.check(1, &Answer::from(5)).is_err());
+
+        ledger.record(1, &Answer::from(16), &Verdict::Correct).unwrap();
+        assert_eq!(ledger.expected(1), Some(&Answer::from(16)));
+        assert_eq!(ledger.expected(2), None);
+    }
+
+    #[test]
+    fn answers_with_separators_round_trip() {
+        let mut ledger = get_ledger(6);
+        ledger.record(1, &Answer::from("a\tb\nc\\t"), &Verdict::Wrong).unwrap();
+        ledger.record(2, &Answer::from("6,1\r\n7,2"), &Verdict::Correct).unwrap();
+
+        let reloaded = Ledger::load(&1013, &6, &get_context()).unwrap();
+        assert_eq!(reloaded.entries(), ledger.entries());
+        assert_eq!(fs::read_to_string(&ledger.path).unwrap().lines().count(), 2);
+    }
+
+    #[test]
+    fn answers_keep_their_kind() {
+        let mut ledger = get_ledger(8);
+        ledger.record(1, &Answer::Text("42".to_string()), &Verdict::Wrong).unwrap();
+        ledger.record(1, &Answer::Text(" padded ".to_string()), &Verdict::Wrong).unwrap();
+        ledger.record(2, &Answer::from(-42), &Verdict::TooLow).unwrap();
+
+        let reloaded = Ledger::load(&1013, &8, &get_context()).unwrap();
+        assert_eq!(reloaded.entries(), ledger.entries());
+        assert!(reloaded.check(1, &Answer::Text("42".to_string())).is_err());
+        assert!(reloaded.check(1, &Answer::from(42)).is_ok());
+    }
+
+    #[test]
+    fn malformed_answers_are_errors() {
+        let context = get_context();
+        let path = context.data_dir.join("1013.day9.answers");
+        fs::create_dir_all(&context.data_dir).unwrap();
+
+        for answer in ["n:12x", "42", "t:bad\\q", "x:1"] {
+            fs::write(&path, format!("1\twrong\t{}\n", answer)).unwrap();
+            assert!(Ledger::load(&1013, &9, &context).is_err(), "{}", answer);
+        }
+    }
+}
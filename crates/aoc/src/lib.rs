@@ -1,15 +1,121 @@
+mod answer;
+pub mod cache;
+mod error;
+pub mod http;
+mod input;
+pub mod ledger;
+pub mod puzzle;
+pub mod registry;
+pub mod samples;
 pub mod session;
+mod solution;
 mod stats;
-
-use reqwest::{Client, Url};
-use std::fs;
+mod submit;
+pub mod unlock;
+
+pub use answer::Answer;
+pub use error::{check_puzzle, AocError, FIRST_YEAR};
+pub use input::Input;
+pub use inventory;
+pub use solution::{DynSolution, Parsed, Solution};
+pub use submit::{submit_answer, Verdict};
+
+use http::HttpClient;
+use reqwest::{StatusCode, Url};
+use session::SessionError;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
+use unlock::Clock;
+
+/// The address of the Advent of Code website.
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
 
 pub struct Context {
     pub session_id: String,
     pub data_dir: PathBuf,
     pub url: Url,
+    /// The named account whose inputs and answers are used, `None` for the default account.
+    pub profile: Option<String>,
+    /// The source of the current time, used to tell whether a puzzle has been released.
+    pub clock: Arc<dyn Clock>,
+    /// The client shared by every request to the site.
+    pub http: HttpClient,
+}
+
+impl Context {
+    /// The directory holding the cached inputs and answers of the profile.
+    ///
+    /// The default profile uses `data_dir` itself while named profiles are kept apart under
+    /// `data_dir/profiles/<name>` so the data of one account never leaks into another.
+    pub fn profile_dir(&self) -> PathBuf {
+        match &self.profile {
+            Some(profile) => self.data_dir.join("profiles").join(profile),
+            None => self.data_dir.clone(),
+        }
+    }
+
+    /// The address of the input for the specified year and day.
+    pub fn input_url(&self, year: &i32, day: &i32) -> Result<Url, AocError> {
+        self.endpoint(&format!("{}/day/{}/input", year, day))
+    }
+
+    /// The address of the puzzle description for the specified year and day.
+    pub fn puzzle_url(&self, year: &i32, day: &i32) -> Result<Url, AocError> {
+        self.endpoint(&format!("{}/day/{}", year, day))
+    }
+
+    /// The address answers for the specified year and day are posted to.
+    pub fn answer_url(&self, year: &i32, day: &i32) -> Result<Url, AocError> {
+        self.endpoint(&format!("{}/day/{}/answer", year, day))
+    }
+
+    /// The address of a leaderboard for the specified year.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year of the event.
+    /// * `private` - The ID of a private leaderboard, `None` for the global leaderboard.
+    pub fn leaderboard_url(&self, year: &i32, private: Option<u64>) -> Result<Url, AocError> {
+        match private {
+            Some(id) => self.endpoint(&format!("{}/leaderboard/private/view/{}.json", year, id)),
+            None => self.endpoint(&format!("{}/leaderboard", year)),
+        }
+    }
+
+    /// Resolves a path against the address of the site.
+    ///
+    /// The address is treated as a directory whether or not it ends with a slash, so a mirror
+    /// hosted beneath a path such as `http://localhost:8080/aoc` keeps its prefix.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the page relative to the root of the site, without a leading slash.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Url)` - The address of the page.
+    /// * `Err(AocError::InvalidUrl)` - If the address of the site cannot have pages beneath it.
+    pub fn endpoint(&self, path: &str) -> Result<Url, AocError> {
+        let mut base = self.url.clone();
+        if !base.path().ends_with('/') {
+            let directory = format!("{}/", base.path());
+            base.set_path(&directory);
+        }
+        base.set_query(None);
+        base.set_fragment(None);
+
+        base.join(path).map_err(|_| AocError::InvalidUrl(self.url.to_string()))
+    }
+}
+
+/// Where the input for a puzzle was retrieved from.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Source {
+    /// The input was already in the cache.
+    Cache,
+    /// The input was downloaded from the site and added to the cache.
+    Site,
 }
 
 /// Fetches the input for the specified year and day.
@@ -22,23 +128,17 @@ pub struct Context {
 ///
 /// # Returns
 ///
-/// * `Ok(Vec<String>)` - If the input is fetched and parsed successfully.
-/// * `Err(String)` - If there is an error fetching or parsing the input.
-pub async fn get_input(year: &i32, day: &i32, context: &Context) -> Result<Vec<String>, String> {
-    println!("Fetching input from for AOC {} Day {}", year, day);
-
-    let body = match get_input_from_cache(&year, &day, &context) {
-        Some(body) => body,
-        None => {
-            let body = get_input_from_site(year, day, &context).await?;
-            add_to_cache(year, day, &body, &context)?;
-            body
-        }
+/// * `Ok((Input, Source))` - The input and where it was retrieved from.
+/// * `Err(AocError)` - If there is an error fetching or caching the input.
+pub async fn fetch_input(year: &i32, day: &i32, context: &Context) -> Result<(Input, Source), AocError> {
+    check_puzzle(year, day)?;
+
+    let (body, source) = match get_input_from_cache(year, day, context)? {
+        Some(body) => (body, Source::Cache),
+        None => (download_input(year, day, context).await?, Source::Site),
     };
 
-    let result: Vec<String> = body.split("\n").map(|s| s.to_string()).collect();
-
-    Ok(result)
+    Ok((Input::new(body), source))
 }
 
 /// Builds the file name for the input data based on the year and day.
@@ -57,6 +157,9 @@ fn build_file_name(year: &i32, day: &i32) -> String {
 
 /// Retrieves the input from the cache if it exists.
 ///
+/// Inputs which do not match their metadata, such as an error page which was cached by mistake,
+/// are treated as missing so they are downloaded again.
+///
 /// # Arguments
 ///
 /// * `year` - A string slice that holds the year of the event.
@@ -64,95 +167,110 @@ fn build_file_name(year: &i32, day: &i32) -> String {
 ///
 /// # Returns
 ///
-/// * `Ok(Some(String))` - If the input file exists and is read successfully.
-/// * `Ok(None)` - If the input file does not exist.
-/// * `Err(String)` - If there is an error reading the input file or creating the directory.
-pub fn get_input_from_cache(year: &i32, day: &i32, context: &Context) -> Option<String> {
-    let input_dir = std::env::current_dir().unwrap().join(&context.data_dir);
-    if !input_dir.exists() {
-        fs::create_dir(&input_dir).unwrap();
-    }
-
-    let input_file = input_dir.join(build_file_name(year, day));
-    return if input_file.exists() {
-        println!("Cache hit");
-        let body = fs::read_to_string(input_file).unwrap();
-        Some(body)
-    } else {
-        None
-    };
+/// * `Ok(Some(String))` - If the input file exists, is read successfully and can be trusted.
+/// * `Ok(None)` - If the input file does not exist or cannot be trusted.
+/// * `Err(AocError)` - If there is an error reading the input file or its metadata.
+pub fn get_input_from_cache(year: &i32, day: &i32, context: &Context) -> Result<Option<String>, AocError> {
+    Ok(cache::read(year, day, context)?.and_then(|(body, entry)| match entry.integrity {
+        cache::Integrity::Invalid(_) => None,
+        _ => Some(body),
+    }))
 }
-/// Cache the input data for later recall.
+
+/// Cache the input data for later recall along with a record of how it was retrieved.
 ///
 /// # Arguments
 ///
 /// * `year` - A string slice that holds the year of the event.
 /// * `day` - A string slice that holds the day of the event.
 /// * `body` - A string slice that holds the input data to be cached.
+/// * `status` - The HTTP status the site answered with.
 ///
 /// # Returns
 ///
 /// * `Ok(())` - If the input is successfully written to the cache.
-/// * `Err(String)` - If there is an error writing the input to the cache.
-pub fn add_to_cache(year: &i32, day: &i32, body: &str, context: &Context) -> Result<(), String> {
-    let input_dir = std::env::current_dir().unwrap().join(&context.data_dir);
-    if !input_dir.exists() {
-        fs::create_dir(&input_dir).map_err(|e| e.to_string())?;
-    }
+/// * `Err(AocError)` - If there is an error writing the input to the cache.
+pub fn add_to_cache(year: &i32, day: &i32, body: &str, status: u16, context: &Context) -> Result<(), AocError> {
+    cache::write(year, day, body, &cache::Metadata::new(body, status, context), context)
+}
 
-    let input_file = input_dir.join(build_file_name(year, day));
-    fs::write(input_file, body).map_err(|e| e.to_string())
+/// Downloads the input for the specified year and day, replacing any cached copy.
+///
+/// # Returns
+///
+/// * `Ok(String)` - The input, which has been added to the cache.
+/// * `Err(AocError)` - If there is an error fetching or caching the input, leaving the cache untouched.
+pub async fn download_input(year: &i32, day: &i32, context: &Context) -> Result<String, AocError> {
+    let (body, status) = request_input(year, day, context).await?;
+    add_to_cache(year, day, &body, status.as_u16(), context)?;
+    Ok(body)
 }
 
-/// Fetches the input from the site for the specified year and day.
+/// Fetches the input from the site for the specified year and day without caching it.
 ///
 /// # Arguments
 ///
-/// * `year` - A string slice that holds the year of the event.
-/// * `day` - A string slice that holds the day of the event.
-/// * `session_id` - A string slice that holds the session ID for authentication.
+/// * `year` - The year of the event.
+/// * `day` - The day of the event.
+/// * `context` - Defines the context to interact with the Advent of Code website, including the
+///   session ID used for authentication.
 ///
 /// # Returns
 ///
-/// * `Ok(String)` - If the input is fetched successfully.
-/// * `Err(String)` - If there is an error fetching the input.
-pub async fn get_input_from_site(
-    year: &i32,
-    day: &i32,
-    context: &Context,
-) -> Result<String, String> {
-    let path = format!("{}{}/day/{}/input", context.url, year, day);
-    println!("Fetching input from {}", path);
-
-    if path.is_empty() {
-        return Err("Path is empty".to_string());
-    }
-
-    let jar = reqwest::cookie::Jar::default();
-
-    // the value of domain needs to be extracted from context.url with the schema removed
-    let domain = context.url.host_str().unwrap();
-    jar.add_cookie_str(
-        format!("session={}; Domain={}; Path=/", context.session_id, domain).as_str(),
-        &context.url,
-    );
+/// * `Ok(String)` - The input exactly as the site returned it.
+/// * `Err(AocError)` - If there is no session, the puzzle is locked or the site refuses the request.
+pub async fn get_input_from_site(year: &i32, day: &i32, context: &Context) -> Result<String, AocError> {
+    Ok(request_input(year, day, context).await?.0)
+}
 
-    let client = Client::builder()
-        .cookie_provider(Arc::new(jar))
-        .build()
-        .map_err(|e| e.to_string())?;
+/// Requests the input from the site, returning it with the status the site answered with.
+async fn request_input(year: &i32, day: &i32, context: &Context) -> Result<(String, StatusCode), AocError> {
+    // inputs differ by user, the site refuses to hand one out without a session
+    if context.session_id.is_empty() {
+        return Err(AocError::MissingInput { year: *year, day: *day });
+    }
+    request_page(year, day, context.input_url(year, day)?, context).await
+}
 
-    let response = client.get(&path).send().await.map_err(|e| e.to_string())?;
-    let status = &response.status();
-    let body = response.text().await.map_err(|e| e.to_string())?;
+/// Requests a page belonging to a puzzle once it has been released, returning the body verbatim
+/// with the status the site answered with.
+async fn request_page(year: &i32, day: &i32, path: Url, context: &Context) -> Result<(String, StatusCode), AocError> {
+    check_puzzle(year, day)?;
+    unlock::check_unlocked(*year, *day, context.clock.as_ref())?;
+
+    let response = context.http.get(path.as_str(), &context.session_id).await?;
+    let status = response.status();
+    let retry_after = response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|value| value.to_str().ok()?.parse::<u64>().ok())
+        .map(Duration::from_secs);
+    // the body is kept verbatim as whitespace can be significant to the puzzle
+    let body = String::from_utf8(response.bytes().await?.to_vec()).map_err(|_| AocError::UnexpectedResponse(format!("{} is not UTF-8 text", path)))?;
     if !status.is_success() {
-        return Err(format!(
-            "Failed to fetch input from {}.  Response: {}",
-            path, body
-        ));
+        return Err(classify_failure(year, day, path.as_str(), status, body, retry_after));
     }
 
-    Ok(body.trim().to_string())
+    Ok((body, status))
+}
+
+/// Determines why the site refused a request.
+fn classify_failure(year: &i32, day: &i32, url: &str, status: StatusCode, body: String, retry_after: Option<Duration>) -> AocError {
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        AocError::RateLimited { retry_after }
+    } else if matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) || session::is_logged_out(&body) {
+        AocError::Auth(SessionError::Expired)
+    } else if status == StatusCode::NOT_FOUND && body.contains("before it unlocks") {
+        AocError::NotYetUnlocked {
+            opens_at: unlock::opens_at(*year, *day),
+        }
+    } else {
+        AocError::Status {
+            url: url.to_string(),
+            status: status.as_u16(),
+            body,
+        }
+    }
 }
 
 pub fn pretty_print<T: std::fmt::Debug>(matrix: &Vec<Vec<T>>) {
@@ -161,10 +279,35 @@ pub fn pretty_print<T: std::fmt::Debug>(matrix: &Vec<Vec<T>>) {
     }
 }
 
+/// Builds a context for tests keeping its data in a directory of the system's temporary directory.
+///
+/// The client does not wait between requests so tests against a mock server stay quick.
+///
+/// # Arguments
+///
+/// * `name` - The directory for the data, relative to the temporary directory.
+/// * `url` - The address of the site, usually a mock server.
+#[cfg(test)]
+pub(crate) fn test_context(name: &str, url: &str) -> Context {
+    Context {
+        session_id: "fake_session_id".to_string(),
+        data_dir: std::env::temp_dir().join(name),
+        url: Url::parse(url).unwrap(),
+        profile: None,
+        clock: Arc::new(unlock::SystemClock),
+        http: HttpClient::new(http::HttpConfig {
+            min_interval: Duration::ZERO,
+            ..http::HttpConfig::default()
+        })
+        .unwrap(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use ctor::ctor;
+    use std::fs;
     use std::sync::LazyLock;
     pub static DATA_DIR: LazyLock<PathBuf> = LazyLock::new(|| get_data_dir());
 
@@ -199,21 +342,167 @@ mod tests {
             .with_body("mocked input")
             .create();
 
-        let context = Context {
-            session_id: "fake_session_id".to_string(),
-            data_dir: DATA_DIR.clone(),
-            url: Url::parse(server.url().as_str()).unwrap(),
-        };
+        let context = test_context("aoc_test", server.url().as_str());
 
         let runtime = tokio::runtime::Runtime::new().unwrap();
         let result = runtime.block_on(get_input_from_site(&2023, &1, &context));
 
-        println!("{:?}", result);
         assert!(result.is_ok());
         assert_eq!(result.unwrap(), "mocked input");
     }
 
+    #[test]
+    fn logged_out_input_is_an_auth_error() {
+        let mut server = mockito::Server::new();
+
+        let _m = server
+            .mock("GET", "/2023/day/2/input")
+            .with_status(400)
+            .with_body("Puzzle inputs differ by user.  Please log in to get your puzzle input.")
+            .create();
+
+        let context = Context {
+            session_id: "expired_session_id".to_string(),
+            ..test_context("aoc_test", server.url().as_str())
+        };
 
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(get_input_from_site(&2023, &2, &context));
+
+        assert!(matches!(result, Err(AocError::Auth(SessionError::Expired))));
+    }
+
+    #[test]
+    fn input_is_fetched_from_a_mirror() {
+        let mut server = mockito::Server::new();
+
+        let _m = server.mock("GET", "/aoc/2023/day/3/input").with_status(200).with_body("mirrored input").create();
+
+        // the prefix is kept even though the address does not end with a slash
+        let context = test_context("aoc_test", &format!("{}/aoc", server.url()));
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(get_input_from_site(&2023, &3, &context));
+
+        assert_eq!(result.ok(), Some("mirrored input".to_string()));
+    }
+
+    #[test]
+    fn endpoints_are_resolved_against_the_base() {
+        let context = |url: &str| test_context("aoc_test", url);
+
+        for base in ["https://adventofcode.com", "https://adventofcode.com/"] {
+            let context = context(base);
+            assert_eq!(context.input_url(&2024, &1).unwrap().as_str(), "https://adventofcode.com/2024/day/1/input");
+            assert_eq!(context.puzzle_url(&2024, &1).unwrap().as_str(), "https://adventofcode.com/2024/day/1");
+            assert_eq!(context.answer_url(&2024, &1).unwrap().as_str(), "https://adventofcode.com/2024/day/1/answer");
+            assert_eq!(context.leaderboard_url(&2024, None).unwrap().as_str(), "https://adventofcode.com/2024/leaderboard");
+            assert_eq!(
+                context.leaderboard_url(&2024, Some(1234)).unwrap().as_str(),
+                "https://adventofcode.com/2024/leaderboard/private/view/1234.json"
+            );
+        }
+
+        for base in ["http://localhost:8080/aoc", "http://localhost:8080/aoc/", "http://localhost:8080/aoc/?cached=1"] {
+            assert_eq!(context(base).input_url(&2024, &25).unwrap().as_str(), "http://localhost:8080/aoc/2024/day/25/input");
+        }
+
+        assert!(matches!(context("mailto:someone@example.com").input_url(&2024, &1), Err(AocError::InvalidUrl(_))));
+    }
+
+    #[test]
+    fn failures_are_classified() {
+        let url = "https://adventofcode.com/2024/day/1/input";
+        let classify = |status: u16, body: &str, retry_after: Option<Duration>| classify_failure(&2024, &1, url, StatusCode::from_u16(status).unwrap(), body.to_string(), retry_after);
+
+        let wait = Some(Duration::from_secs(30));
+        assert!(matches!(classify(429, "", wait), AocError::RateLimited { retry_after } if retry_after == wait));
+        assert!(matches!(classify(403, "", None), AocError::Auth(SessionError::Expired)));
+        assert!(matches!(
+            classify(404, "Please don't repeatedly request this endpoint before it unlocks!", None),
+            AocError::NotYetUnlocked { .. }
+        ));
+        assert!(matches!(classify(500, "oops", None), AocError::Status { status: 500, .. }));
+    }
+
+    #[test]
+    fn locked_puzzles_are_not_fetched() {
+        let opens_at = unlock::opens_at(2024, 5);
+        let context = Context {
+            clock: Arc::new(unlock::FixedClock(opens_at - Duration::from_secs(60))),
+            ..test_context("aoc_test", "http://127.0.0.1:9")
+        };
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(get_input_from_site(&2024, &5, &context));
+
+        assert!(matches!(result, Err(AocError::NotYetUnlocked { opens_at: at }) if at == opens_at));
+    }
+
+    #[test]
+    fn invalid_puzzles_are_not_fetched() {
+        let context = test_context("aoc_test", "http://127.0.0.1:9");
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(fetch_input(&2024, &26, &context));
+
+        assert!(matches!(result, Err(AocError::InvalidPuzzle { year: 2024, day: 26 })));
+    }
+
+    #[test]
+    fn fetch_input_reports_source() {
+        let mut server = mockito::Server::new();
+
+        let mock = server.mock("GET", "/2015/day/20/input").with_status(200).with_body("first\nsecond").expect(1).create();
+
+        let context = test_context("aoc_test", server.url().as_str());
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (input, source) = runtime.block_on(fetch_input(&2015, &20, &context)).unwrap();
+        assert_eq!(input.lines(), vec!["first", "second"]);
+        assert_eq!(source, Source::Site);
+
+        let (input, source) = runtime.block_on(fetch_input(&2015, &20, &context)).unwrap();
+        assert_eq!(input.lines(), vec!["first", "second"]);
+        assert_eq!(source, Source::Cache);
+        mock.assert();
+    }
+
+    #[test]
+    fn input_is_cached_verbatim() {
+        let mut server = mockito::Server::new();
+
+        let body = "  1 2\r\n3 4\r\n\r\n";
+        let _m = server.mock("GET", "/2015/day/21/input").with_status(200).with_body(body).expect(1).create();
+
+        let context = test_context("aoc_test", server.url().as_str());
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let (fresh, _) = runtime.block_on(fetch_input(&2015, &21, &context)).unwrap();
+        let (cached, _) = runtime.block_on(fetch_input(&2015, &21, &context)).unwrap();
+
+        assert_eq!(get_input_from_cache(&2015, &21, &context).unwrap(), Some(body.to_string()));
+        assert_eq!(fresh.raw(), body);
+        assert_eq!(fresh.lines(), vec!["  1 2", "3 4", ""]);
+        assert_eq!(cached, fresh);
+    }
+
+    #[test]
+    fn inputs_are_not_requested_without_a_session() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("GET", "/2015/day/22/input").expect(0).create();
+
+        let context = Context {
+            session_id: String::new(),
+            ..test_context("aoc_test", server.url().as_str())
+        };
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(fetch_input(&2015, &22, &context));
+
+        assert!(matches!(result, Err(AocError::MissingInput { year: 2015, day: 22 })));
+        mock.assert();
+    }
 
     #[test]
     fn cache_hit() {
@@ -221,17 +510,13 @@ mod tests {
         let day = 8;
         let body = "test input";
 
-        let context = Context {
-            session_id: "fake_session_id".to_string(),
-            data_dir: DATA_DIR.clone(),
-            url: Url::parse("https://adventofcode.com").unwrap(),
-        };
+        let context = test_context("aoc_test", "https://adventofcode.com");
 
-        let result = add_to_cache(&year, &day, body, &context);
+        let result = add_to_cache(&year, &day, body, 200, &context);
         assert!(result.is_ok());
 
         let result = get_input_from_cache(&year, &day, &context);
-        assert_eq!(result, Some(body.to_string()));
+        assert_eq!(result.unwrap(), Some(body.to_string()));
     }
 
     #[test]
@@ -239,14 +524,10 @@ mod tests {
         let year = 9999;
         let day = 1;
 
-        let context = Context {
-            session_id: "fake_session_id".to_string(),
-            data_dir: DATA_DIR.clone(),
-            url: Url::parse("https://adventofcode.com").unwrap(),
-        };
+        let context = test_context("aoc_test", "https://adventofcode.com");
 
         let result = get_input_from_cache(&year, &day, &context);
-        assert_eq!(result, None);
+        assert_eq!(result.unwrap(), None);
     }
 
     #[test]
@@ -255,13 +536,9 @@ mod tests {
         let day = 2;
         let body = "test input";
 
-        let context = Context {
-            session_id: "fake_session_id".to_string(),
-            data_dir: DATA_DIR.clone(),
-            url: Url::parse("https://adventofcode.com").unwrap(),
-        };
+        let context = test_context("aoc_test", "https://adventofcode.com");
 
-        let result = add_to_cache(&year, &day, body, &context);
+        let result = add_to_cache(&year, &day, body, 200, &context);
         assert!(result.is_ok());
 
         let cached_file = context.data_dir.join(build_file_name(&year, &day));
@@ -274,16 +551,12 @@ mod tests {
         let year = 1013;
         let day = 7;
 
-        let context = Context {
-            session_id: "fake_session_id".to_string(),
-            data_dir: DATA_DIR.clone(),
-            url: Url::parse("https://adventofcode.com").unwrap(),
-        };
+        let context = test_context("aoc_test", "https://adventofcode.com");
 
-        let result = add_to_cache(&year, &day, "original", &context);
+        let result = add_to_cache(&year, &day, "original", 200, &context);
         assert!(result.is_ok());
         let body = "altered";
-        let result = add_to_cache(&year, &day, body, &context);
+        let result = add_to_cache(&year, &day, body, 200, &context);
         assert!(result.is_ok());
 
         let cached_file = context.data_dir.join(build_file_name(&year, &day));
@@ -291,6 +564,25 @@ mod tests {
         assert_eq!(cached_body, body);
     }
 
+    #[test]
+    fn profiles_have_separate_caches() {
+        let year = 1013;
+        let day = 9;
+        let context = |profile: Option<&str>| Context {
+            profile: profile.map(|p| p.to_string()),
+            ..test_context("aoc_test", "https://adventofcode.com")
+        };
+
+        let result = add_to_cache(&year, &day, "alice input", 200, &context(Some("alice")));
+        assert!(result.is_ok());
+
+        assert_eq!(get_input_from_cache(&year, &day, &context(Some("alice"))).unwrap(), Some("alice input".to_string()));
+        assert_eq!(get_input_from_cache(&year, &day, &context(Some("bob"))).unwrap(), None);
+        assert_eq!(get_input_from_cache(&year, &day, &context(None)).unwrap(), None);
+        assert_eq!(context(Some("alice")).profile_dir(), DATA_DIR.join("profiles").join("alice"));
+        assert_eq!(context(None).profile_dir(), DATA_DIR.clone());
+    }
+
     #[test]
     fn test_build_file_name() {
         let year = 1013;
@@ -0,0 +1,257 @@
+use crate::cache::cache_dir;
+use crate::ledger::Ledger;
+use crate::{request_page, AocError, Context, Source};
+use reqwest::Url;
+use scraper::{ElementRef, Html, Node, Selector};
+use std::fs;
+
+/// Retrieves the description of a puzzle as Markdown, downloading it when it is not cached.
+///
+/// The second part of a puzzle is only revealed once the first has been solved, so a cached
+/// description without it is downloaded again when the ledger records an accepted answer to part 1.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `day` - The day of the event.
+/// * `refresh` - Download the description even if it is cached.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `Ok((String, Source))` - The description and where it was retrieved from.
+/// * `Err(AocError)` - If the description cannot be downloaded, understood or cached.
+pub async fn get_description(year: &i32, day: &i32, refresh: bool, context: &Context) -> Result<(String, Source), AocError> {
+    if !refresh {
+        if let (Some(page), Some(markdown)) = (get_page_from_cache(year, day, context)?, read(year, day, "md", context)?) {
+            if count_parts(&page) > 1 || Ledger::load(year, day, context)?.correct(1).is_none() {
+                return Ok((markdown, Source::Cache));
+            }
+        }
+    }
+
+    let url = context.puzzle_url(year, day)?;
+    let (page, _) = request_page(year, day, url.clone(), context).await?;
+    let markdown = to_markdown(&page, &url).ok_or_else(|| AocError::UnexpectedResponse(format!("{} has no puzzle description", url)))?;
+
+    let dir = cache_dir(context)?;
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(file_name(year, day, "html")), &page)?;
+    fs::write(dir.join(file_name(year, day, "md")), &markdown)?;
+
+    Ok((markdown, Source::Site))
+}
+
+/// Retrieves the page of a puzzle as it was last downloaded by [`get_description`].
+///
+/// # Returns
+///
+/// * `Ok(Some(String))` - The HTML of the page if it has been cached.
+/// * `Ok(None)` - If the page has not been downloaded yet.
+/// * `Err(AocError)` - If the cached page cannot be read.
+pub fn get_page_from_cache(year: &i32, day: &i32, context: &Context) -> Result<Option<String>, AocError> {
+    read(year, day, "html", context)
+}
+
+/// Converts the description of a puzzle to Markdown.
+///
+/// # Arguments
+///
+/// * `page` - The HTML of the puzzle page.
+/// * `base` - The address of the page, used to resolve relative links.
+///
+/// # Returns
+///
+/// * `Some(String)` - The Markdown of every part of the description revealed on the page.
+/// * `None` - If the page does not hold a description.
+pub fn to_markdown(page: &str, base: &Url) -> Option<String> {
+    let document = Html::parse_document(page);
+    let articles = Selector::parse("article.day-desc").unwrap();
+
+    let mut markdown = String::new();
+    for article in document.select(&articles) {
+        write_blocks(article, base, &mut markdown);
+    }
+
+    if markdown.is_empty() {
+        None
+    } else {
+        Some(format!("{}\n", markdown.trim_end()))
+    }
+}
+
+/// Reads the title of a puzzle from its page, such as `Print Queue` for `--- Day 5: Print Queue ---`.
+///
+/// # Returns
+///
+/// * `Some(String)` - The title of the puzzle.
+/// * `None` - If the page does not hold a description.
+pub fn title(page: &str) -> Option<String> {
+    let document = Html::parse_document(page);
+    let heading = document.select(&Selector::parse("article.day-desc h2").unwrap()).next()?.text().collect::<String>();
+    let title = heading.trim().trim_matches('-').trim();
+    Some(title.split_once(": ").map_or(title, |(_, title)| title).to_string())
+}
+
+/// The number of parts of the description revealed on a page.
+fn count_parts(page: &str) -> usize {
+    Html::parse_document(page).select(&Selector::parse("article.day-desc").unwrap()).count()
+}
+
+fn write_blocks(element: ElementRef, base: &Url, markdown: &mut String) {
+    for child in element.children() {
+        let Some(child) = ElementRef::wrap(child) else {
+            if let Node::Text(text) = child.value() {
+                if !text.trim().is_empty() {
+                    markdown.push_str(&format!("{}\n\n", text.trim()));
+                }
+            }
+            continue;
+        };
+
+        match child.value().name() {
+            "h2" => markdown.push_str(&format!("## {}\n\n", write_inline(child, base))),
+            "pre" => {
+                let code = child.text().collect::<String>();
+                markdown.push_str(&format!("```\n{}\n```\n\n", code.trim_end_matches('\n')));
+            }
+            "ul" | "ol" => {
+                for item in child.children().filter_map(ElementRef::wrap) {
+                    markdown.push_str(&format!("- {}\n", write_inline(item, base).trim()));
+                }
+                markdown.push('\n');
+            }
+            _ => markdown.push_str(&format!("{}\n\n", write_inline(child, base).trim())),
+        }
+    }
+}
+
+fn write_inline(element: ElementRef, base: &Url) -> String {
+    let mut markdown = String::new();
+    for child in element.children() {
+        let Some(child) = ElementRef::wrap(child) else {
+            if let Node::Text(text) = child.value() {
+                markdown.push_str(text);
+            }
+            continue;
+        };
+
+        match child.value().name() {
+            "em" => markdown.push_str(&format!("*{}*", write_inline(child, base))),
+            "code" => {
+                let code = child.text().collect::<String>();
+                // highlighted values such as the answers to the examples are wrapped in both
+                let emphasized = child.children().filter_map(ElementRef::wrap).any(|e| e.value().name() == "em" && e.text().collect::<String>() == code);
+                if emphasized {
+                    markdown.push_str(&format!("*`{}`*", code));
+                } else {
+                    markdown.push_str(&format!("`{}`", code));
+                }
+            }
+            "a" => {
+                let text = write_inline(child, base);
+                match child.value().attr("href").and_then(|href| base.join(href).ok()) {
+                    Some(href) => markdown.push_str(&format!("[{}]({})", text, href)),
+                    None => markdown.push_str(&text),
+                }
+            }
+            "br" => markdown.push('\n'),
+            _ => markdown.push_str(&write_inline(child, base)),
+        }
+    }
+    markdown
+}
+
+fn read(year: &i32, day: &i32, extension: &str, context: &Context) -> Result<Option<String>, AocError> {
+    let path = cache_dir(context)?.join(file_name(year, day, extension));
+    if path.exists() {
+        Ok(Some(fs::read_to_string(path)?))
+    } else {
+        Ok(None)
+    }
+}
+
+fn file_name(year: &i32, day: &i32, extension: &str) -> String {
+    format!("{}.day{}.{}", year, day, extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_context;
+    use crate::{Answer, Verdict};
+
+    const PART_ONE: &str = r#"<article class="day-desc"><h2>--- Day 5: Print Queue ---</h2><p>The <em>first section</em> specifies the rules, see <a href="/2024/day/4">yesterday</a>:</p>
+<pre><code>47|53
+97|13
+</code></pre>
+<ul><li>The first update, <code>75,47,61</code>, is in order.</li><li>Adding them up gives <code><em>143</em></code>.</li></ul>
+</article>"#;
+
+    const PART_TWO: &str = r#"<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Find the <span title="easter egg">middle</span> page.</p></article>"#;
+
+    fn page(articles: &[&str]) -> String {
+        format!("<html><body><main>{}<p>Your puzzle answer was <code>42</code>.</p></main></body></html>", articles.join("\n"))
+    }
+
+    fn get_context(name: &str, url: &str) -> Context {
+        let context = test_context(&format!("aoc_puzzle_test/{}", name), url);
+        let _ = fs::remove_dir_all(&context.data_dir);
+        context
+    }
+
+    #[test]
+    fn description_is_converted() {
+        let base = Url::parse("https://adventofcode.com/2024/day/5").unwrap();
+        let markdown = to_markdown(&page(&[PART_ONE, PART_TWO]), &base).unwrap();
+
+        assert_eq!(
+            markdown,
+            "## --- Day 5: Print Queue ---\n\n\
+             The *first section* specifies the rules, see [yesterday](https://adventofcode.com/2024/day/4):\n\n\
+             ```\n47|53\n97|13\n```\n\n\
+             - The first update, `75,47,61`, is in order.\n\
+             - Adding them up gives *`143`*.\n\n\
+             ## --- Part Two ---\n\n\
+             Find the middle page.\n"
+        );
+    }
+
+    #[test]
+    fn title_is_read() {
+        assert_eq!(title(&page(&[PART_ONE, PART_TWO])).as_deref(), Some("Print Queue"));
+        assert_eq!(title("<html><body><p>Not here</p></body></html>"), None);
+    }
+
+    #[test]
+    fn pages_without_a_description_are_rejected() {
+        let base = Url::parse("https://adventofcode.com/2024/day/5").unwrap();
+        assert_eq!(to_markdown("<html><body><p>Not here</p></body></html>", &base), None);
+    }
+
+    // this is not using tokio::test due to runtime block_on collisions when using mockito
+    #[test]
+    fn part_two_is_downloaded_once_part_one_is_solved() {
+        let mut server = mockito::Server::new();
+        let context = get_context("part_two", &server.url());
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let first = server.mock("GET", "/2015/day/5").with_status(200).with_body(page(&[PART_ONE])).expect(1).create();
+        let (markdown, source) = runtime.block_on(get_description(&2015, &5, false, &context)).unwrap();
+        assert_eq!(source, Source::Site);
+        assert!(!markdown.contains("Part Two"));
+
+        let (_, source) = runtime.block_on(get_description(&2015, &5, false, &context)).unwrap();
+        assert_eq!(source, Source::Cache);
+        first.assert();
+        assert!(get_page_from_cache(&2015, &5, &context).unwrap().unwrap().contains("Print Queue"));
+
+        first.remove();
+        let second = server.mock("GET", "/2015/day/5").with_status(200).with_body(page(&[PART_ONE, PART_TWO])).expect(1).create();
+        Ledger::load(&2015, &5, &context).unwrap().record(1, &Answer::from(143), &Verdict::Correct).unwrap();
+        let (markdown, source) = runtime.block_on(get_description(&2015, &5, false, &context)).unwrap();
+        assert_eq!(source, Source::Site);
+        assert!(markdown.contains("Part Two"));
+        second.assert();
+    }
+}
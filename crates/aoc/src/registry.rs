@@ -0,0 +1,131 @@
+use crate::solution::DynSolution;
+
+/// An entry in the solution registry.
+///
+/// Instances are created through the [`crate::register!`] macro rather than by hand.
+pub struct Registration(pub &'static dyn DynSolution);
+
+inventory::collect!(Registration);
+
+/// Registers a [`crate::Solution`] with the registry so the runner can discover it.
+///
+/// # Example
+///
+/// ```ignore
+/// pub struct Day1;
+///
+/// impl aoc::Solution for Day1 { /* ... */ }
+///
+/// aoc::register!(Day1);
+/// ```
+#[macro_export]
+macro_rules! register {
+    ($solution:expr) => {
+        $crate::inventory::submit! {
+            $crate::registry::Registration(&$solution)
+        }
+    };
+}
+
+/// Lists every registered solution ordered by year and day.
+///
+/// # Returns
+///
+/// * `Vec<&'static dyn DynSolution>` - The registered solutions.
+pub fn solutions() -> Vec<&'static dyn DynSolution> {
+    let mut result: Vec<&'static dyn DynSolution> = inventory::iter::<Registration>.into_iter().map(|r| r.0).collect();
+    result.sort_by_key(|s| (s.year(), s.day()));
+    result
+}
+
+/// Lists the years with at least one registered solution.
+///
+/// # Returns
+///
+/// * `Vec<i32>` - The years in order.
+pub fn years() -> Vec<i32> {
+    let mut years: Vec<i32> = solutions().iter().map(|s| s.year()).collect();
+    years.dedup();
+    years
+}
+
+/// Finds the solution registered for the specified year and day.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `day` - The day of the event.
+///
+/// # Returns
+///
+/// * `Some(&'static dyn DynSolution)` - If a solution is registered for the puzzle.
+/// * `None` - If no solution is registered for the puzzle.
+pub fn find(year: i32, day: i32) -> Option<&'static dyn DynSolution> {
+    solutions().into_iter().find(|s| s.year() == year && s.day() == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Input, Solution};
+
+    struct Sample;
+
+    impl Solution for Sample {
+        type Input = Vec<String>;
+        type Part1 = i32;
+        type Part2 = String;
+
+        fn year(&self) -> i32 {
+            1013
+        }
+
+        fn day(&self) -> i32 {
+            3
+        }
+
+        fn title(&self) -> &'static str {
+            "Sample"
+        }
+
+        fn parse(&self, input: &Input) -> Vec<String> {
+            input.lines().iter().map(|line| line.to_string()).collect()
+        }
+
+        fn part1(&self, input: &Vec<String>) -> i32 {
+            input.len() as i32
+        }
+
+        fn part2(&self, input: &Vec<String>) -> String {
+            input.join(",")
+        }
+    }
+
+    crate::register!(Sample);
+
+    #[test]
+    fn registered_solution_is_found() {
+        let solution = find(1013, 3).unwrap();
+        let input = solution.parse(&Input::from("a\nb\n"));
+
+        assert_eq!(solution.title(), "Sample");
+        assert_eq!(solution.part1(&input), 2);
+        assert_eq!(solution.part2(&input), "a,b");
+    }
+
+    #[test]
+    fn unregistered_solution_is_missing() {
+        assert!(find(1013, 4).is_none());
+        assert!(find(1014, 3).is_none());
+    }
+
+    #[test]
+    fn solutions_are_listed() {
+        assert!(solutions().iter().any(|s| s.year() == 1013 && s.day() == 3));
+    }
+
+    #[test]
+    fn years_are_listed_once() {
+        assert_eq!(years(), vec![1013]);
+    }
+}
@@ -0,0 +1,365 @@
+use crate::{Answer, AocError, Input, Solution};
+use scraper::{ElementRef, Html, Selector};
+use std::fs;
+use std::path::Path;
+
+/// An example from a puzzle description along with the answers it is given.
+///
+/// Examples are stored as fixtures in a directory, each as `example{n}.dat` holding the input with
+/// an `example{n}.answers` file beside it holding one tab separated `part answer` line per part.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Example {
+    /// The name of the example, such as `example1`.
+    pub name: String,
+    pub input: Input,
+    /// The expected answers to part 1 and part 2, if the description gives them.
+    pub answers: [Option<Answer>; 2],
+}
+
+impl Example {
+    /// The expected answer to a part, if the description gives one.
+    pub fn answer(&self, part: i32) -> Option<&Answer> {
+        match part {
+            1 | 2 => self.answers[part as usize - 1].as_ref(),
+            _ => None,
+        }
+    }
+}
+
+/// Loads the examples extracted from the puzzle description of the calling day crate.
+///
+/// Expands to the examples stored in the `src/samples` directory of the crate, in order.
+///
+/// # Panics
+///
+/// If the fixtures cannot be read.
+#[macro_export]
+macro_rules! examples {
+    () => {
+        $crate::samples::load(::std::path::Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/src/samples"))).expect("Unable to load the examples")
+    };
+}
+
+/// Reads a sample stored beside the calling source file as an [`Input`].
+///
+/// # Example
+///
+/// ```ignore
+/// let input = aoc::sample!("sample.dat");
+/// ```
+#[macro_export]
+macro_rules! sample {
+    ($file:literal) => {
+        $crate::Input::from(include_str!($file))
+    };
+}
+
+/// Generates tests solving a sample with a [`crate::Solution`] and comparing the answers.
+///
+/// A test is generated for each part given, named `sample_part1_valid` and `sample_part2_valid`.
+/// Prefixing a name places the tests in a module of that name so a day can check several samples,
+/// and `examples` in place of a file checks every example extracted from the puzzle description.
+///
+/// # Example
+///
+/// ```ignore
+/// aoc::sample_test!(Day3, "sample.dat", part1 = 161);
+/// aoc::sample_test!(second: Day3, "sample2.dat", part2 = 48);
+/// aoc::sample_test!(Day3, examples);
+/// ```
+#[macro_export]
+macro_rules! sample_test {
+    ($name:ident: $($rest:tt)*) => {
+        mod $name {
+            use super::*;
+
+            $crate::sample_test!($($rest)*);
+        }
+    };
+    ($solution:expr, examples) => {
+        #[test]
+        fn examples_valid() {
+            $crate::samples::check_examples(&$solution, &$crate::examples!());
+        }
+    };
+    ($solution:expr, $file:literal $(, part1 = $part1:expr)? $(, part2 = $part2:expr)? $(,)?) => {
+        $(
+            #[test]
+            fn sample_part1_valid() {
+                let input = $crate::sample!($file);
+                let parsed = $crate::Solution::parse(&$solution, &input);
+                assert_eq!($crate::Solution::part1(&$solution, &parsed), $part1);
+            }
+        )?
+        $(
+            #[test]
+            fn sample_part2_valid() {
+                let input = $crate::sample!($file);
+                let parsed = $crate::Solution::parse(&$solution, &input);
+                assert_eq!($crate::Solution::part2(&$solution, &parsed), $part2);
+            }
+        )?
+    };
+}
+
+/// Solves every example and compares the answers with those given by the description.
+///
+/// # Panics
+///
+/// If there are no examples or a solution gives a different answer.
+pub fn check_examples<S: Solution>(solution: &S, examples: &[Example]) {
+    assert!(!examples.is_empty(), "No examples have been extracted, run `runner samples` for the day");
+
+    for example in examples {
+        let parsed = solution.parse(&example.input);
+        if let Some(expected) = example.answer(1) {
+            assert_eq!(&solution.part1(&parsed).into(), expected, "part 1 of {}", example.name);
+        }
+        if let Some(expected) = example.answer(2) {
+            assert_eq!(&solution.part2(&parsed).into(), expected, "part 2 of {}", example.name);
+        }
+    }
+}
+
+/// Extracts the examples from a puzzle page.
+///
+/// Every `<pre><code>` block of the description becomes an example. The answers are the
+/// highlighted values, `<code><em>`, of each part: the last one following an example belongs to
+/// it, and a part without examples of its own answers the example which answered the part before.
+/// The highlighting is not always an answer, so the fixtures are worth a look before relying on them.
+///
+/// # Arguments
+///
+/// * `page` - The HTML of the puzzle page.
+///
+/// # Returns
+///
+/// * `Vec<Example>` - The examples in the order they appear.
+pub fn extract(page: &str) -> Vec<Example> {
+    let document = Html::parse_document(page);
+    let articles = Selector::parse("article.day-desc").unwrap();
+
+    let mut examples: Vec<Example> = vec![];
+    for (part, article) in document.select(&articles).take(2).enumerate() {
+        let mut current = examples.iter().rposition(|e| part > 0 && e.answers[part - 1].is_some());
+
+        for element in article.descendants().filter_map(ElementRef::wrap) {
+            if element.value().name() == "pre" {
+                examples.push(Example {
+                    name: format!("example{}", examples.len() + 1),
+                    input: Input::new(element.text().collect::<String>()),
+                    answers: [None, None],
+                });
+                current = Some(examples.len() - 1);
+            } else if let (Some(answer), Some(index)) = (highlighted(element), current) {
+                examples[index].answers[part] = Some(answer);
+            }
+        }
+    }
+
+    examples
+}
+
+/// The value of an element highlighted as an answer, either `<code><em>` or `<em><code>`.
+fn highlighted(element: ElementRef) -> Option<Answer> {
+    let name = element.value().name();
+    if !matches!(name, "code" | "em") || element.ancestors().filter_map(ElementRef::wrap).any(|a| a.value().name() == "pre") {
+        return None;
+    }
+
+    let inner = match name {
+        "code" => "em",
+        _ => "code",
+    };
+    let text = element.text().collect::<String>();
+    let mut children = element.children().filter_map(ElementRef::wrap);
+    match (children.next(), children.next()) {
+        (Some(child), None) if child.value().name() == inner && child.text().collect::<String>() == text => text.parse().ok(),
+        _ => None,
+    }
+}
+
+/// Writes examples as fixtures, replacing the examples already in the directory.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the fixtures are written.
+/// * `Err(AocError)` - If the directory cannot be written.
+pub fn save(dir: &Path, examples: &[Example]) -> Result<(), AocError> {
+    fs::create_dir_all(dir)?;
+    for file in fs::read_dir(dir)? {
+        let path = file?.path();
+        if path.file_name().and_then(|n| n.to_str()).is_some_and(|n| parse_name(n).is_some()) {
+            fs::remove_file(path)?;
+        }
+    }
+
+    for example in examples {
+        fs::write(dir.join(format!("{}.dat", example.name)), example.input.raw())?;
+        let answers = (1..=2).filter_map(|part| example.answer(part).map(|answer| format!("{}\t{}\n", part, answer))).collect::<String>();
+        fs::write(dir.join(format!("{}.answers", example.name)), answers)?;
+    }
+
+    Ok(())
+}
+
+/// Reads the examples stored as fixtures in a directory, ordered by their number.
+///
+/// # Returns
+///
+/// * `Ok(Vec<Example>)` - The examples, empty if the directory does not exist.
+/// * `Err(AocError)` - If a fixture cannot be read.
+pub fn load(dir: &Path) -> Result<Vec<Example>, AocError> {
+    if !dir.exists() {
+        return Ok(vec![]);
+    }
+
+    let mut numbers = vec![];
+    for file in fs::read_dir(dir)? {
+        let name = file?.file_name();
+        if let Some(number) = name.to_str().filter(|n| n.ends_with(".dat")).and_then(parse_name) {
+            numbers.push(number);
+        }
+    }
+    numbers.sort();
+
+    numbers
+        .into_iter()
+        .map(|number| {
+            let name = format!("example{}", number);
+            let input = Input::new(fs::read_to_string(dir.join(format!("{}.dat", name)))?);
+            let answers_file = dir.join(format!("{}.answers", name));
+            let mut answers = [None, None];
+            if answers_file.exists() {
+                for line in fs::read_to_string(answers_file)?.lines() {
+                    if let Some((part @ (1 | 2), answer)) = line.split_once('\t').and_then(|(p, a)| Some((p.parse::<usize>().ok()?, a))) {
+                        answers[part - 1] = answer.parse().ok();
+                    }
+                }
+            }
+            Ok(Example { name, input, answers })
+        })
+        .collect()
+}
+
+/// The number of an example fixture file, such as `3` for `example3.answers`.
+fn parse_name(name: &str) -> Option<u32> {
+    let (stem, extension) = name.rsplit_once('.')?;
+    if !matches!(extension, "dat" | "answers") {
+        return None;
+    }
+    stem.strip_prefix("example")?.parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<html><body><main>
+<article class="day-desc"><h2>--- Day 9: Disk Fragmenter ---</h2>
+<p>For example, the disk map <code>12345</code> would represent:</p>
+<pre><code>0..111....22222
+</code></pre>
+<p>The first example above, <code>2333133121414131402</code>, represents these individual blocks:</p>
+<pre><code>2333133121414131402
+</code></pre>
+<p>Continuing, the <em>first</em> few blocks' position multiplied by its file ID number are <code>0 * 0 = 0</code>.</p>
+<p>In this example, the checksum is the sum of these, <code><em>1928</em></code>.</p>
+</article>
+<p>Your puzzle answer was <code>6366665108136</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<p>The process of updating the filesystem checksum is the same; now, this example's checksum would be <em><code>2858</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn examples_are_extracted() {
+        let examples = extract(PAGE);
+
+        assert_eq!(examples.len(), 2);
+        assert_eq!(examples[0].input.raw(), "0..111....22222\n");
+        assert_eq!(examples[0].answers, [None, None]);
+        assert_eq!(examples[1].name, "example2");
+        assert_eq!(examples[1].input.first_line(), "2333133121414131402");
+        assert_eq!(examples[1].answer(1), Some(&Answer::from(1928)));
+        assert_eq!(examples[1].answer(2), Some(&Answer::from(2858)));
+    }
+
+    #[test]
+    fn pages_without_examples_have_none() {
+        assert!(extract("<html><body><article class=\"day-desc\"><p>No <code><em>1</em></code></p></article></body></html>").is_empty());
+    }
+
+    struct Disk;
+
+    impl Solution for Disk {
+        type Input = Vec<i128>;
+        type Part1 = usize;
+        type Part2 = i128;
+
+        fn year(&self) -> i32 {
+            1013
+        }
+
+        fn day(&self) -> i32 {
+            9
+        }
+
+        fn title(&self) -> &'static str {
+            "Disk"
+        }
+
+        fn parse(&self, input: &Input) -> Vec<i128> {
+            input.ints()
+        }
+
+        fn part1(&self, input: &Vec<i128>) -> usize {
+            input.len()
+        }
+
+        fn part2(&self, input: &Vec<i128>) -> i128 {
+            input.iter().sum()
+        }
+    }
+
+    fn example(input: &str, answers: [Option<Answer>; 2]) -> Example {
+        Example {
+            name: "example1".to_string(),
+            input: Input::from(input),
+            answers,
+        }
+    }
+
+    #[test]
+    fn examples_are_checked() {
+        check_examples(&Disk, &[example("1 2 3", [Some(Answer::from(3)), Some(Answer::from(6))]), example("4", [None, Some(Answer::from(4))])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "part 2 of example1")]
+    fn wrong_answers_fail() {
+        check_examples(&Disk, &[example("1 2 3", [Some(Answer::from(3)), Some(Answer::from(7))])]);
+    }
+
+    #[test]
+    #[should_panic(expected = "No examples")]
+    fn missing_examples_fail() {
+        check_examples(&Disk, &[]);
+    }
+
+    #[test]
+    fn examples_round_trip() {
+        let dir = std::env::temp_dir().join("aoc_samples_test");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("example7.dat"), "stale").unwrap();
+        fs::write(dir.join("notes.txt"), "kept").unwrap();
+
+        let examples = extract(PAGE);
+        save(&dir, &examples).unwrap();
+
+        assert_eq!(load(&dir).unwrap(), examples);
+        assert_eq!(fs::read_to_string(dir.join("example2.answers")).unwrap(), "1\t1928\n2\t2858\n");
+        assert!(dir.join("notes.txt").exists());
+    }
+}
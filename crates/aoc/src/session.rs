@@ -1,60 +1,351 @@
-    use std::fs;
-    use std::path::PathBuf;
-
-    /// Retrieves the session ID from the `.session` file.
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(String)` - If the session ID is read successfully.
-    /// * `Err(String)` - If there is an error reading the session ID.
-    pub fn get_session_id(root: &PathBuf) -> Option<String> {
-        // check if .session file exists
-        let session_path = root.join(".session");
-        return if fs::metadata(&session_path).is_ok() {
-            Some(fs::read_to_string(&session_path).unwrap())
-        } else {
-            None
+use crate::Context;
+use reqwest::StatusCode;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// The environment variable holding the session ID of the default profile.
+pub const SESSION_VARIABLE: &str = "AOC_SESSION";
+
+/// Where a session ID was found.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    /// Passed on the command line.
+    Flag,
+    /// Read from the named environment variable.
+    Environment(String),
+    /// Read from a file, either `.session` in the current directory or the user's config directory.
+    File(PathBuf),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Flag => write!(f, "the command line"),
+            Source::Environment(variable) => write!(f, "the {} environment variable", variable),
+            Source::File(path) => write!(f, "{}", path.display()),
         }
     }
+}
 
-    #[cfg(test)]
-    mod tests {
-        use super::*;
+/// A session ID and where it was found.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Session {
+    pub id: String,
+    pub source: Source,
+}
 
-        use std::{path::PathBuf, sync::LazyLock};
-        pub static DATA_DIR: LazyLock<PathBuf> = LazyLock::new(|| get_data_dir());
+/// Why a session cannot be used.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SessionError {
+    /// No session ID was provided.
+    Missing,
+    /// The site no longer accepts the session ID.
+    Expired,
+    /// The site could not be reached or answered unexpectedly.
+    Request(String),
+}
 
-        fn get_data_dir() -> PathBuf {
-            std::env::temp_dir().join("aoc_session_test")
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionError::Missing => write!(f, "No session ID was provided"),
+            SessionError::Expired => write!(f, "The session has expired or is not valid"),
+            SessionError::Request(e) => write!(f, "Unable to validate the session: {}", e),
         }
+    }
+}
 
-        #[test]
-        fn test_get_session_id() {
-            create_session_file();
-            let session_id = get_session_id(&DATA_DIR.clone());
-            assert_eq!(session_id, Some("fake_session_id".to_string()));
-        }
+/// Retrieves the session ID from the `.session` file.
+///
+/// # Returns
+///
+/// * `Ok(String)` - If the session ID is read successfully.
+/// * `Err(String)` - If there is an error reading the session ID.
+pub fn get_session_id(root: &PathBuf) -> Option<String> {
+    read_session_file(&root.join(".session"))
+}
 
-        #[test]
-        fn when_no_session_file_exists() {
-            clear_session_file();
+/// Checks a profile name is safe to use as part of a file name and environment variable.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the name only contains letters, digits, `-` and `_`.
+/// * `Err(String)` - If the name is empty or contains any other character.
+pub fn validate_profile(profile: &str) -> Result<(), String> {
+    if !profile.is_empty() && profile.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        Ok(())
+    } else {
+        Err(format!("{} is not a valid profile name, use letters, digits, - and _", profile))
+    }
+}
 
-            let session_id = get_session_id(&DATA_DIR.clone());
-            assert_eq!(session_id, None);
-        }
+/// The environment variable holding the session ID of a profile, e.g. `AOC_SESSION_ALICE`.
+pub fn session_variable(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!("{}_{}", SESSION_VARIABLE, profile.to_uppercase().replace('-', "_")),
+        None => SESSION_VARIABLE.to_string(),
+    }
+}
 
-        fn clear_session_file() {
-            
-            let session_path = DATA_DIR.join(".session");
-            if fs::metadata(&session_path).is_ok() {
-                fs::remove_file(&session_path).unwrap();
-            }
-        }
+/// The name of the file holding the session ID of a profile, e.g. `.session.alice`.
+pub fn session_file_name(profile: Option<&str>) -> String {
+    match profile {
+        Some(profile) => format!(".session.{}", profile),
+        None => ".session".to_string(),
+    }
+}
+
+/// The file in the user's config directory holding the session ID of a profile, e.g.
+/// `~/.config/aoc/session` or `~/.config/aoc/session.alice`.
+pub fn config_file(profile: Option<&str>) -> Option<PathBuf> {
+    let name = session_file_name(profile);
+    dirs::config_dir().map(|dir| dir.join("aoc").join(name.trim_start_matches('.')))
+}
+
+/// Finds the session ID of a profile by checking, in order, the command line, the environment
+/// variable, the session file in `root` and the session file in the user's config directory.
+///
+/// # Arguments
+///
+/// * `flag` - The session ID passed on the command line.
+/// * `profile` - The named profile, or `None` for the default profile.
+/// * `root` - The directory to look for a session file in.
+///
+/// # Returns
+///
+/// * `Some(Session)` - The first non blank session ID found.
+/// * `None` - If none of the sources provides a session ID.
+pub fn resolve(flag: Option<&str>, profile: Option<&str>, root: &Path) -> Option<Session> {
+    let variable = session_variable(profile);
+    let value = std::env::var(&variable).ok();
+    resolve_from(flag, (variable, value), root.join(session_file_name(profile)), config_file(profile))
+}
+
+fn resolve_from(flag: Option<&str>, variable: (String, Option<String>), file: PathBuf, config: Option<PathBuf>) -> Option<Session> {
+    let session = |id: String, source: Source| Some(Session { id, source });
+
+    if let Some(id) = flag.and_then(non_blank) {
+        return session(id, Source::Flag);
+    }
+    if let Some(id) = variable.1.as_deref().and_then(non_blank) {
+        return session(id, Source::Environment(variable.0));
+    }
+
+    [Some(file), config]
+        .into_iter()
+        .flatten()
+        .find_map(|path| read_session_file(&path).map(|id| Session { id, source: Source::File(path) }))
+}
+
+/// Checks the site still accepts the session by requesting a page only available when logged in.
+///
+/// # Arguments
+///
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the site recognises the session.
+/// * `Err(SessionError)` - If there is no session, it has expired or the site cannot be reached.
+pub async fn validate(context: &Context) -> Result<(), SessionError> {
+    if context.session_id.is_empty() {
+        return Err(SessionError::Missing);
+    }
+
+    let path = context.endpoint("settings").map_err(|e| SessionError::Request(e.to_string()))?;
+    let login = context.endpoint("auth").map_err(|e| SessionError::Request(e.to_string()))?;
+    let response = context.http.get(path.as_str(), &context.session_id).await.map_err(|e| SessionError::Request(e.to_string()))?;
+
+    // logged out visitors are redirected to the login page
+    let redirected = response.url().path().starts_with(login.path());
+    let status = response.status();
+    let body = response.text().await.map_err(|e| SessionError::Request(e.to_string()))?;
+
+    if redirected || matches!(status, StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN) || is_logged_out(&body) {
+        Err(SessionError::Expired)
+    } else if !status.is_success() {
+        Err(SessionError::Request(format!("{} responded with {}", path, status)))
+    } else {
+        Ok(())
+    }
+}
+
+/// Determines whether a page was served to a visitor who is not logged in, including the
+/// message returned in place of a puzzle input.
+pub fn is_logged_out(body: &str) -> bool {
+    body.contains("Please log in") || body.contains("[Log In]")
+}
+
+fn read_session_file(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().as_deref().and_then(non_blank)
+}
+
+fn non_blank(id: &str) -> Option<String> {
+    let id = id.trim();
+    if id.is_empty() {
+        None
+    } else {
+        Some(id.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_context;
+
+    use std::{path::PathBuf, sync::LazyLock};
+    pub static DATA_DIR: LazyLock<PathBuf> = LazyLock::new(|| get_data_dir());
+
+    fn get_data_dir() -> PathBuf {
+        std::env::temp_dir().join("aoc_session_test")
+    }
+
+    #[test]
+    fn test_get_session_id() {
+        create_session_file();
+        let session_id = get_session_id(&DATA_DIR.clone());
+        assert_eq!(session_id, Some("fake_session_id".to_string()));
+    }
+
+    #[test]
+    fn when_no_session_file_exists() {
+        clear_session_file();
 
-        fn create_session_file() {
-            let session_path = DATA_DIR.join(".session");
-            println!("Creating test file at {:?}", session_path);
-            fs::write(&session_path, "fake_session_id").unwrap();
+        let session_id = get_session_id(&DATA_DIR.clone());
+        assert_eq!(session_id, None);
+    }
+
+    #[test]
+    fn flag_takes_precedence() {
+        let session = resolve_from(Some(" from_flag\n"), variable(Some("from_variable")), PathBuf::from("missing"), None);
+        assert_eq!(
+            session,
+            Some(Session {
+                id: "from_flag".to_string(),
+                source: Source::Flag
+            })
+        );
+    }
+
+    #[test]
+    fn blank_sources_are_skipped() {
+        let session = resolve_from(Some("  "), variable(Some("from_variable\n")), PathBuf::from("missing"), None);
+        assert_eq!(
+            session,
+            Some(Session {
+                id: "from_variable".to_string(),
+                source: Source::Environment(SESSION_VARIABLE.to_string())
+            })
+        );
+    }
+
+    #[test]
+    fn files_are_checked_in_order() {
+        let dir = std::env::temp_dir().join("aoc_session_chain_test");
+        let config = dir.join("config");
+        fs::create_dir_all(&config).unwrap();
+        fs::write(dir.join(".session"), "\n").unwrap();
+        fs::write(config.join("session"), "from_config\r\n").unwrap();
+
+        let session = resolve_from(None, variable(None), dir.join(".session"), Some(config.join("session")));
+        assert_eq!(
+            session,
+            Some(Session {
+                id: "from_config".to_string(),
+                source: Source::File(config.join("session"))
+            })
+        );
+
+        fs::write(dir.join(".session"), "from_cwd").unwrap();
+        let session = resolve_from(None, variable(None), dir.join(".session"), Some(config.join("session")));
+        assert_eq!(session.map(|s| s.source), Some(Source::File(dir.join(".session"))));
+    }
+
+    #[test]
+    fn when_no_source_provides_a_session() {
+        assert_eq!(resolve_from(None, variable(None), PathBuf::from("missing"), None), None);
+    }
+
+    #[test]
+    fn profiles_use_their_own_sources() {
+        assert_eq!(session_variable(Some("team-a")), "AOC_SESSION_TEAM_A");
+        assert_eq!(session_file_name(Some("alice")), ".session.alice");
+        assert_eq!(session_variable(None), SESSION_VARIABLE);
+        assert_eq!(session_file_name(None), ".session");
+        assert!(config_file(Some("alice")).is_none_or(|path| path.ends_with("aoc/session.alice")));
+    }
+
+    #[test]
+    fn profile_names_are_validated() {
+        assert!(validate_profile("alice").is_ok());
+        assert!(validate_profile("team_2-b").is_ok());
+        assert!(validate_profile("").is_err());
+        assert!(validate_profile("../alice").is_err());
+    }
+
+    fn validate_with(server: &mockito::Server, session_id: &str) -> Result<(), SessionError> {
+        let context = Context {
+            session_id: session_id.to_string(),
+            ..test_context("aoc_session_test", server.url().as_str())
+        };
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(validate(&context))
+    }
+
+    // this is not using tokio::test due to runtime block_on collisions when using mockito
+    #[test]
+    fn valid_session() {
+        let mut server = mockito::Server::new();
+        let _m = server.mock("GET", "/settings").with_status(200).with_body("<a href=\"/2024/auth/logout\">[Log Out]</a>").create();
+
+        assert_eq!(validate_with(&server, "fake_session_id"), Ok(()));
+    }
+
+    #[test]
+    fn redirect_to_login_is_expired() {
+        let mut server = mockito::Server::new();
+        let _m = server.mock("GET", "/settings").with_status(302).with_header("location", "/auth/login").create();
+        let _login = server.mock("GET", "/auth/login").with_status(200).with_body("<p>To play, please identify yourself</p>").create();
+
+        assert_eq!(validate_with(&server, "fake_session_id"), Err(SessionError::Expired));
+    }
+
+    #[test]
+    fn please_log_in_is_expired() {
+        let mut server = mockito::Server::new();
+        let _m = server
+            .mock("GET", "/settings")
+            .with_status(400)
+            .with_body("Puzzle inputs differ by user.  Please log in to get your puzzle input.")
+            .create();
+
+        assert_eq!(validate_with(&server, "fake_session_id"), Err(SessionError::Expired));
+    }
+
+    #[test]
+    fn missing_session_is_not_requested() {
+        let server = mockito::Server::new();
+        assert_eq!(validate_with(&server, ""), Err(SessionError::Missing));
+    }
+
+    fn variable(value: Option<&str>) -> (String, Option<String>) {
+        (SESSION_VARIABLE.to_string(), value.map(|v| v.to_string()))
+    }
+
+    fn clear_session_file() {
+        let session_path = DATA_DIR.join(".session");
+        if fs::metadata(&session_path).is_ok() {
+            fs::remove_file(&session_path).unwrap();
         }
+    }
 
+    fn create_session_file() {
+        fs::create_dir_all(DATA_DIR.as_path()).unwrap();
+        let session_path = DATA_DIR.join(".session");
+        println!("Creating test file at {:?}", session_path);
+        fs::write(&session_path, "fake_session_id").unwrap();
     }
+}
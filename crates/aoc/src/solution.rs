@@ -0,0 +1,109 @@
+use crate::{Answer, Input};
+use std::any::Any;
+
+/// A solver for a single Advent of Code puzzle.
+///
+/// Each day crate implements this trait on a unit struct and hands it to [`crate::register!`]
+/// so the runner can discover it without being edited for every new day.
+///
+/// Solving happens in phases: the input is parsed once into a model which both parts then share,
+/// allowing each phase to be timed on its own.
+pub trait Solution {
+    /// The model produced by parsing the puzzle input.
+    type Input: 'static;
+    /// The type produced by the first part of the puzzle.
+    type Part1: Into<Answer>;
+    /// The type produced by the second part of the puzzle.
+    type Part2: Into<Answer>;
+
+    /// The year of the event the puzzle belongs to.
+    fn year(&self) -> i32;
+
+    /// The day of the event the puzzle belongs to.
+    fn day(&self) -> i32;
+
+    /// The title of the puzzle as published on the site.
+    fn title(&self) -> &'static str;
+
+    /// Parses the puzzle input into the model shared by both parts.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The puzzle input.
+    fn parse(&self, input: &Input) -> Self::Input;
+
+    /// Solves the first part of the puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The parsed puzzle input.
+    fn part1(&self, input: &Self::Input) -> Self::Part1;
+
+    /// Solves the second part of the puzzle.
+    ///
+    /// # Arguments
+    ///
+    /// * `input` - The parsed puzzle input.
+    fn part2(&self, input: &Self::Input) -> Self::Part2;
+}
+
+/// The parsed input of a puzzle with its type erased.
+pub type Parsed = Box<dyn Any>;
+
+/// Object safe view of a [`Solution`] used by the registry.
+///
+/// The parsed input is type erased and the answers are converted into an [`Answer`] so solutions
+/// with different models and answer types can live side by side.
+pub trait DynSolution: Sync {
+    fn year(&self) -> i32;
+    fn day(&self) -> i32;
+    fn title(&self) -> &'static str;
+    fn parse(&self, input: &Input) -> Parsed;
+
+    /// Solves the first part of the puzzle.
+    ///
+    /// # Panics
+    ///
+    /// If `input` was not produced by [`DynSolution::parse`] on the same solution.
+    fn part1(&self, input: &Parsed) -> Answer;
+
+    /// Solves the second part of the puzzle.
+    ///
+    /// # Panics
+    ///
+    /// If `input` was not produced by [`DynSolution::parse`] on the same solution.
+    fn part2(&self, input: &Parsed) -> Answer;
+}
+
+impl<T> DynSolution for T
+where
+    T: Solution + Sync,
+{
+    fn year(&self) -> i32 {
+        Solution::year(self)
+    }
+
+    fn day(&self) -> i32 {
+        Solution::day(self)
+    }
+
+    fn title(&self) -> &'static str {
+        Solution::title(self)
+    }
+
+    fn parse(&self, input: &Input) -> Parsed {
+        Box::new(Solution::parse(self, input))
+    }
+
+    fn part1(&self, input: &Parsed) -> Answer {
+        Solution::part1(self, downcast::<T>(input)).into()
+    }
+
+    fn part2(&self, input: &Parsed) -> Answer {
+        Solution::part2(self, downcast::<T>(input)).into()
+    }
+}
+
+fn downcast<T: Solution>(input: &Parsed) -> &T::Input {
+    input.downcast_ref::<T::Input>().expect("The input was parsed by a different solution")
+}
@@ -21,12 +21,12 @@ mod tests {
     use super::*;
 
     #[test]
-    fn combinations_with_repetition_valid () {
+    fn combinations_with_repetition_valid() {
         assert_eq!(combinations_with_repetition(3, 6), 28);
     }
 
     #[test]
-    fn combinations_valid () {
-         assert_eq!(combinations(5, 3), 10);
+    fn combinations_valid() {
+        assert_eq!(combinations(5, 3), 10);
     }
 }
@@ -0,0 +1,218 @@
+use crate::{check_puzzle, Answer, AocError, Context};
+use std::time::Duration;
+
+/// The site's verdict on a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    /// The answer is correct.
+    Correct,
+    /// The answer is incorrect and larger than the correct answer.
+    TooHigh,
+    /// The answer is incorrect and smaller than the correct answer.
+    TooLow,
+    /// The answer is incorrect with no further hint.
+    Wrong,
+    /// An answer was submitted too recently and another may be tried after the wait.
+    RateLimited(Duration),
+    /// The part has already been solved or is not unlocked yet.
+    AlreadySolved,
+}
+
+impl std::fmt::Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "Correct"),
+            Verdict::TooHigh => write!(f, "Incorrect, too high"),
+            Verdict::TooLow => write!(f, "Incorrect, too low"),
+            Verdict::Wrong => write!(f, "Incorrect"),
+            Verdict::RateLimited(wait) => write!(f, "Rate limited, wait {}s before trying again", wait.as_secs()),
+            Verdict::AlreadySolved => write!(f, "Already solved"),
+        }
+    }
+}
+
+/// Submits an answer to the site for the specified year, day and part.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `day` - The day of the event.
+/// * `part` - The part of the puzzle the answer is for.
+/// * `answer` - The answer to submit.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `Ok(Verdict)` - If the answer was submitted and the response understood.
+/// * `Err(AocError)` - If the answer could not be submitted or the response was not recognized.
+pub async fn submit_answer(year: &i32, day: &i32, part: &i32, answer: &Answer, context: &Context) -> Result<Verdict, AocError> {
+    check_puzzle(year, day)?;
+    if !answer.is_solved() {
+        return Err(AocError::Unsolved { part: *part });
+    }
+
+    let path = context.answer_url(year, day)?;
+    let form = [("level", part.to_string()), ("answer", answer.to_string())];
+    let response = context.http.post_form(path.as_str(), &context.session_id, &form).await?;
+    let status = response.status();
+    let body = response.text().await?;
+    if !status.is_success() {
+        return Err(AocError::Status {
+            url: path.to_string(),
+            status: status.as_u16(),
+            body,
+        });
+    }
+
+    parse_verdict(&body)
+}
+
+/// Interprets the HTML returned by the site after an answer is submitted.
+fn parse_verdict(body: &str) -> Result<Verdict, AocError> {
+    if body.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if body.contains("You gave an answer too recently") {
+        Ok(Verdict::RateLimited(parse_wait(body)))
+    } else if body.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::AlreadySolved)
+    } else if body.contains("That's not the right answer") {
+        Ok(if body.contains("your answer is too high") {
+            Verdict::TooHigh
+        } else if body.contains("your answer is too low") {
+            Verdict::TooLow
+        } else {
+            Verdict::Wrong
+        })
+    } else {
+        Err(AocError::UnexpectedResponse(body.to_string()))
+    }
+}
+
+/// Extracts the wait from a message such as `You have 1m 5s left to wait.`
+fn parse_wait(body: &str) -> Duration {
+    let text = match (body.find("You have "), body.find(" left to wait")) {
+        (Some(start), Some(end)) if start + 9 <= end => &body[start + 9..end],
+        _ => return Duration::ZERO,
+    };
+
+    let seconds = text
+        .split_whitespace()
+        .filter_map(|token| {
+            let value = token.strip_suffix(['h', 'm', 's'])?.parse::<u64>().ok()?;
+            match token.chars().last()? {
+                'h' => Some(value * 3600),
+                'm' => Some(value * 60),
+                _ => Some(value),
+            }
+        })
+        .sum();
+
+    Duration::from_secs(seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_context;
+
+    fn wrap(message: &str) -> String {
+        format!("<!DOCTYPE html><html><body><main><article><p>{}</p></article></main></body></html>", message)
+    }
+
+    #[test]
+    fn correct_answer() {
+        let body = wrap("That's the right answer!  You are one gold star closer to finding the Chief Historian.");
+        assert_eq!(parse_verdict(&body).ok(), Some(Verdict::Correct));
+    }
+
+    #[test]
+    fn answer_too_high() {
+        let body = wrap("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(parse_verdict(&body).ok(), Some(Verdict::TooHigh));
+    }
+
+    #[test]
+    fn answer_too_low() {
+        let body = wrap("That's not the right answer; your answer is too low.");
+        assert_eq!(parse_verdict(&body).ok(), Some(Verdict::TooLow));
+    }
+
+    #[test]
+    fn answer_wrong() {
+        let body = wrap("That's not the right answer.  If you're stuck, make sure you're using the full input data.");
+        assert_eq!(parse_verdict(&body).ok(), Some(Verdict::Wrong));
+    }
+
+    #[test]
+    fn answer_rate_limited() {
+        let body = wrap("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.");
+        assert_eq!(parse_verdict(&body).ok(), Some(Verdict::RateLimited(Duration::from_secs(65))));
+    }
+
+    #[test]
+    fn wait_ignores_other_text() {
+        assert_eq!(parse_wait("You have 1h 2m 3s left to wait."), Duration::from_secs(3723));
+        assert_eq!(parse_wait("You have 5s — about 5 é left to wait."), Duration::from_secs(5));
+        assert_eq!(parse_wait("You have é left to wait."), Duration::ZERO);
+    }
+
+    #[test]
+    fn answer_already_solved() {
+        let body = wrap("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_verdict(&body).ok(), Some(Verdict::AlreadySolved));
+    }
+
+    #[test]
+    fn unrecognized_response() {
+        assert!(matches!(parse_verdict(&wrap("Puzzle inputs differ by user.")), Err(AocError::UnexpectedResponse(_))));
+    }
+
+    // this is not using tokio::test due to runtime block_on collisions when using mockito
+    #[test]
+    fn test_submit_answer() {
+        let mut server = mockito::Server::new();
+
+        let _m = server
+            .mock("POST", "/2023/day/1/answer")
+            .match_header("cookie", "session=fake_session_id")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("level".to_string(), "2".to_string()),
+                mockito::Matcher::UrlEncoded("answer".to_string(), "42".to_string()),
+            ]))
+            .with_status(200)
+            .with_header("content-type", "text/html")
+            .with_body(wrap("That's the right answer!"))
+            .create();
+
+        let context = test_context("aoc_test", server.url().as_str());
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(submit_answer(&2023, &1, &2, &Answer::from(42), &context));
+
+        assert_eq!(result.ok(), Some(Verdict::Correct));
+    }
+
+    #[test]
+    fn server_errors_are_not_resubmitted() {
+        let mut server = mockito::Server::new();
+        let mock = server.mock("POST", "/2023/day/1/answer").with_status(502).expect(1).create();
+
+        let context = test_context("aoc_test", server.url().as_str());
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(submit_answer(&2023, &1, &1, &Answer::from(42), &context));
+
+        assert!(matches!(result, Err(AocError::Status { status: 502, .. })));
+        mock.assert();
+    }
+
+    #[test]
+    fn unsolved_is_not_submitted() {
+        let context = test_context("aoc_test", "http://127.0.0.1:9");
+
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        let result = runtime.block_on(submit_answer(&2023, &1, &1, &Answer::Unsolved, &context));
+
+        assert!(matches!(result, Err(AocError::Unsolved { part: 1 })));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::AocError;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Puzzles are released at midnight UTC-5, which is 05:00 UTC.
+const RELEASE_HOUR_UTC: u64 = 5;
+
+/// A source of the current time, replaceable so unlock times can be tested.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> SystemTime;
+}
+
+/// The clock of the operating system.
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// A clock which is stopped at a point in time.
+pub struct FixedClock(pub SystemTime);
+
+impl Clock for FixedClock {
+    fn now(&self) -> SystemTime {
+        self.0
+    }
+}
+
+/// The moment the puzzle for the specified year and day is released.
+pub fn opens_at(year: i32, day: i32) -> SystemTime {
+    let days = days_from_civil(year as i64, 12, day as i64) as u64;
+    UNIX_EPOCH + Duration::from_secs(days * 86400 + RELEASE_HOUR_UTC * 3600)
+}
+
+/// Ensures the puzzle for the specified year and day has been released.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `day` - The day of the event.
+/// * `clock` - The source of the current time.
+///
+/// # Returns
+///
+/// * `Ok(())` - If the puzzle has been released.
+/// * `Err(AocError::NotYetUnlocked)` - If the puzzle opens in the future.
+pub fn check_unlocked(year: i32, day: i32, clock: &dyn Clock) -> Result<(), AocError> {
+    let opens_at = opens_at(year, day);
+    if clock.now() < opens_at {
+        Err(AocError::NotYetUnlocked { opens_at })
+    } else {
+        Ok(())
+    }
+}
+
+/// How long until the puzzle is released, zero if it already has been.
+pub fn time_until_unlock(year: i32, day: i32, clock: &dyn Clock) -> Duration {
+    opens_at(year, day).duration_since(clock.now()).unwrap_or(Duration::ZERO)
+}
+
+/// Formats a point in time as `YYYY-MM-DD HH:MM:SS UTC`.
+pub fn format_utc(time: SystemTime) -> String {
+    let seconds = time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    let (year, month, day) = civil_from_days((seconds / 86400) as i64);
+    let time_of_day = seconds % 86400;
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC", year, month, day, time_of_day / 3600, time_of_day / 60 % 60, time_of_day % 60)
+}
+
+// the conversions between dates and days since the unix epoch follow
+// https://howardhinnant.github.io/date_algorithms.html
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 };
+    (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-12-01 05:00:00 UTC
+    const DAY_ONE_2024: u64 = 1733029200;
+
+    fn at(seconds: u64) -> FixedClock {
+        FixedClock(UNIX_EPOCH + Duration::from_secs(seconds))
+    }
+
+    #[test]
+    fn puzzles_open_at_midnight_eastern() {
+        assert_eq!(opens_at(2024, 1), UNIX_EPOCH + Duration::from_secs(DAY_ONE_2024));
+        assert_eq!(opens_at(2024, 25), UNIX_EPOCH + Duration::from_secs(DAY_ONE_2024 + 24 * 86400));
+        assert_eq!(format_utc(opens_at(2015, 1)), "2015-12-01 05:00:00 UTC");
+    }
+
+    #[test]
+    fn locked_until_release() {
+        let result = check_unlocked(2024, 1, &at(DAY_ONE_2024 - 1));
+        assert!(matches!(result, Err(AocError::NotYetUnlocked { opens_at }) if opens_at == UNIX_EPOCH + Duration::from_secs(DAY_ONE_2024)));
+        assert_eq!(time_until_unlock(2024, 1, &at(DAY_ONE_2024 - 90)), Duration::from_secs(90));
+    }
+
+    #[test]
+    fn unlocked_from_release() {
+        assert!(check_unlocked(2024, 1, &at(DAY_ONE_2024)).is_ok());
+        assert_eq!(time_until_unlock(2024, 1, &at(DAY_ONE_2024 + 1)), Duration::ZERO);
+    }
+}
@@ -1,19 +1,34 @@
 use std::env;
-use std::fs::File;
+use std::fs::{self, File};
 use std::io::{Result, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 fn main() -> Result<()> {
-    println!("Copy .session file to target directory");
-
     let out = &PathBuf::from(env::var_os("OUT_DIR").unwrap());
-    let session_path = PathBuf::from("../../.session");
 
-    if session_path.exists() {
-        File::create(out.join(".session"))?
-            .write_all(include_bytes!("../../.session"))?;
-    } else {
-        println!("cargo::warning=.session not found, Create the file with the session key prior to execution");
+    link_solutions(out)
+}
+
+/// Generates `solutions.rs` which links every solution crate listed in the runner's dependencies.
+///
+/// Solutions register themselves with the `aoc` registry, however a crate that is never referenced
+/// is dropped by the linker and its registration with it.  Referencing each crate here keeps the
+/// runner free of a per day edit.
+fn link_solutions(out: &Path) -> Result<()> {
+    println!("cargo::rerun-if-changed=Cargo.toml");
+
+    let manifest = fs::read_to_string("Cargo.toml")?;
+    let mut file = File::create(out.join("solutions.rs"))?;
+
+    for line in manifest.lines() {
+        let [name, value] = line.splitn(2, '=').map(|x| x.trim()).collect::<Vec<_>>()[..] else {
+            continue;
+        };
+
+        if name != "aoc" && value.starts_with('{') && value.contains("path =") {
+            writeln!(file, "use {} as _;", name.replace('-', "_"))?;
+        }
     }
+
     Ok(())
 }
@@ -0,0 +1,532 @@
+use crate::table::Table;
+use aoc::ledger::Ledger;
+use aoc::{Answer, AocError, Context, DynSolution, Input, Source};
+use std::any::Any;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
+
+/// The answers and timings from solving one day.
+pub struct Solved {
+    pub parse: Duration,
+    pub part1: (Answer, Duration),
+    pub part2: (Answer, Duration),
+}
+
+impl Solved {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1.1 + self.part2.1
+    }
+}
+
+/// Why a day could not be run.
+pub enum Failure {
+    NotImplemented,
+    Input(AocError),
+    /// The solution did not finish within the time limit.
+    TimedOut(Duration),
+    /// The solution panicked, with the message it gave.
+    Panicked(String),
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::NotImplemented => write!(f, "not implemented"),
+            Failure::Input(e) => write!(f, "{}", e),
+            Failure::TimedOut(limit) => write!(f, "timed out after {:?}", limit),
+            Failure::Panicked(message) => write!(f, "panicked: {}", message),
+        }
+    }
+}
+
+/// The result of running one day.
+pub struct Outcome {
+    pub year: i32,
+    pub day: i32,
+    pub title: &'static str,
+    /// Where the input was retrieved from, if it was retrieved.
+    pub source: Option<Source>,
+    /// The answers the ledger expects for each part.
+    pub expected: [Option<Answer>; 2],
+    pub result: Result<Solved, Failure>,
+}
+
+impl Outcome {
+    /// Determines if a solved part differs from the answer the ledger expects.
+    pub fn differs(&self, part: i32) -> bool {
+        match (&self.result, &self.expected[part as usize - 1]) {
+            (Ok(solved), Some(expected)) => expected != if part == 1 { &solved.part1.0 } else { &solved.part2.0 },
+            _ => false,
+        }
+    }
+}
+
+/// How the solutions of several days are run.
+#[derive(Clone, Copy)]
+pub struct Schedule {
+    /// Run the days on separate threads.
+    pub parallel: bool,
+    /// The longest a day may take, or `None` to wait for as long as it takes. A day which takes
+    /// longer keeps running in the background until the process exits.
+    pub limit: Option<Duration>,
+}
+
+/// A list of days such as `1-10,13`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Days(pub Vec<i32>);
+
+impl FromStr for Days {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_days(s).map(Days)
+    }
+}
+
+/// Parses a list of days such as `1-10,13` into the individual days.
+///
+/// # Returns
+///
+/// * `Ok(Vec<i32>)` - The days in ascending order without duplicates.
+/// * `Err(String)` - If a day or range cannot be parsed or falls outside of 1 to 25.
+fn parse_days(text: &str) -> Result<Vec<i32>, String> {
+    let mut days = vec![];
+
+    for part in text.split(',').map(|p| p.trim()).filter(|p| !p.is_empty()) {
+        let parse = |x: &str| match x.trim().parse::<i32>() {
+            Ok(day) if (1..=25).contains(&day) => Ok(day),
+            _ => Err(format!("{} is not a valid day, expected 1 to 25", x.trim())),
+        };
+        match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (parse(start)?, parse(end)?);
+                if start > end {
+                    return Err(format!("{} is not a valid range of days", part));
+                }
+                days.extend(start..=end);
+            }
+            None => days.push(parse(part)?),
+        }
+    }
+
+    if days.is_empty() {
+        return Err("No days were specified".to_string());
+    }
+
+    days.sort();
+    days.dedup();
+    Ok(days)
+}
+
+/// Measures how long a function takes to run.
+pub fn time<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start_time = Instant::now();
+    let result = f();
+    (result, start_time.elapsed())
+}
+
+fn solve(solution: &dyn DynSolution, input: &Input) -> Solved {
+    let (parsed, parse) = time(|| solution.parse(input));
+    Solved {
+        parse,
+        part1: time(|| solution.part1(&parsed)),
+        part2: time(|| solution.part2(&parsed)),
+    }
+}
+
+/// Starts solving a day on a thread of its own so it can be given up on when it takes too long.
+fn spawn(solution: &'static dyn DynSolution, input: &Input) -> (JoinHandle<()>, Receiver<Solved>) {
+    let (sender, receiver) = mpsc::channel();
+    let input = input.clone();
+    let handle = std::thread::spawn(move || {
+        let _ = sender.send(solve(solution, &input));
+    });
+    (handle, receiver)
+}
+
+/// Waits for a day started at `start` to be solved, giving up once the limit has passed.
+///
+/// A thread cannot be stopped, so a day which is given up on keeps running until it finishes or
+/// the process exits, taking up a core for the rest of the run.
+fn wait((handle, receiver): (JoinHandle<()>, Receiver<Solved>), start: Instant, limit: Option<Duration>) -> Result<Solved, Failure> {
+    let solved = match limit {
+        Some(limit) => match receiver.recv_timeout((start + limit).saturating_duration_since(Instant::now())) {
+            Err(RecvTimeoutError::Timeout) => return Err(Failure::TimedOut(limit)),
+            received => received.ok(),
+        },
+        None => receiver.recv().ok(),
+    };
+
+    // the result is only missing when the solution panicked before sending it
+    match solved {
+        Some(solved) => Ok(solved),
+        None => Err(Failure::Panicked(handle.join().err().map(panic_message).unwrap_or_default())),
+    }
+}
+
+/// The message a solution panicked with.
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().map_or("unknown cause".to_string(), |m| m.to_string()),
+    }
+}
+
+/// Solves a day, giving up when it takes longer than the limit or panics.
+///
+/// # Arguments
+///
+/// * `solution` - The solution for the day.
+/// * `input` - The puzzle input.
+/// * `limit` - The longest the day may take, or `None` to wait for as long as it takes.
+///
+/// # Returns
+///
+/// * `Ok(Solved)` - The answers and timings.
+/// * `Err(Failure::TimedOut)` - If the day did not finish in time.
+/// * `Err(Failure::Panicked)` - If the solution panicked.
+pub fn solve_within(solution: &'static dyn DynSolution, input: &Input, limit: Option<Duration>) -> Result<Solved, Failure> {
+    wait(spawn(solution, input), Instant::now(), limit)
+}
+
+/// Looks up the registered solutions for the specified days.
+///
+/// # Returns
+///
+/// * `(Vec<&dyn DynSolution>, Vec<i32>)` - The solutions found and the days without one.
+fn lookup(year: i32, days: Option<Vec<i32>>) -> (Vec<&'static dyn DynSolution>, Vec<i32>) {
+    match days {
+        Some(days) => {
+            let (mut found, mut missing) = (vec![], vec![]);
+            for day in days {
+                match aoc::registry::find(year, day) {
+                    Some(s) => found.push(s),
+                    None => missing.push(day),
+                }
+            }
+            (found, missing)
+        }
+        None => (aoc::registry::solutions().into_iter().filter(|s| s.year() == year).collect(), vec![]),
+    }
+}
+
+/// Selects the registered solutions for the specified days, explaining which days are missing.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `days` - The days to select, or `None` to select every registered day of the year.
+pub fn select(year: i32, days: Option<Vec<i32>>) -> Vec<&'static dyn DynSolution> {
+    let selected_all = days.is_none();
+    let (solutions, missing) = lookup(year, days);
+    missing.iter().for_each(|day| println!("{}", not_implemented(year, Some(*day))));
+    if selected_all && solutions.is_empty() {
+        println!("{}", not_implemented(year, None));
+    }
+    solutions
+}
+
+/// Explains that a day, or a whole year, has no solution, naming the years which have solutions
+/// when the year has none.
+pub fn not_implemented(year: i32, day: Option<i32>) -> String {
+    let message = match day {
+        Some(day) => format!("{} Day {} not implemented", year, day),
+        None => format!("No day of {} is implemented", year),
+    };
+
+    let years = aoc::registry::years();
+    if years.is_empty() || years.contains(&year) {
+        message
+    } else {
+        format!("{}, there are solutions for {}", message, years.iter().map(|y| y.to_string()).collect::<Vec<_>>().join(", "))
+    }
+}
+
+/// Fetches the input for a day, reporting whether it came from the cache or the site.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `day` - The day of the event.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `Ok(Input)` - If the input is fetched successfully.
+/// * `Err(AocError)` - If there is an error fetching or caching the input.
+pub async fn get_input(year: i32, day: i32, context: &Context) -> Result<Input, AocError> {
+    println!("Fetching input for {} Day {}", year, day);
+
+    let (input, source) = aoc::fetch_input(&year, &day, context).await?;
+    match source {
+        Source::Cache => println!("Cache hit"),
+        Source::Site => println!("Downloaded input from {}", context.input_url(&year, &day)?),
+    }
+
+    Ok(input)
+}
+
+/// Runs several days of a year and collects their answers and timings.
+///
+/// Inputs are always fetched one at a time to keep the load on the site down, however the
+/// solutions may be run in parallel once every input is available.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `days` - The days to run, or `None` to run every registered day of the year.
+/// * `schedule` - How the days are run.
+/// * `quiet` - Do not report progress while fetching the inputs.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `Vec<Outcome>` - The outcome of each day in order, including the days which could not be run.
+pub async fn execute(year: i32, days: Option<Vec<i32>>, schedule: Schedule, quiet: bool, context: &Context) -> Vec<Outcome> {
+    let (solutions, missing) = if quiet { lookup(year, days) } else { (select(year, days), vec![]) };
+
+    let mut outcomes: Vec<Outcome> = missing
+        .into_iter()
+        .map(|day| Outcome {
+            year,
+            day,
+            title: "",
+            source: None,
+            expected: [None, None],
+            result: Err(Failure::NotImplemented),
+        })
+        .collect();
+
+    let mut inputs = vec![];
+    for solution in solutions {
+        let day = solution.day();
+        // the answers the ledger expects are read up front so a damaged ledger is reported like a missing input
+        let fetched = match Ledger::load(&year, &day, context) {
+            Ok(ledger) => {
+                let expected = [ledger.expected(1).cloned(), ledger.expected(2).cloned()];
+                if quiet {
+                    aoc::fetch_input(&year, &day, context).await.map(|(input, source)| (input, Some(source), expected))
+                } else {
+                    get_input(year, day, context).await.map(|input| (input, None, expected))
+                }
+            }
+            Err(e) => Err(e),
+        };
+
+        match fetched {
+            Ok((input, source, expected)) => inputs.push((solution, input, source, expected)),
+            Err(e) => {
+                if !quiet {
+                    println!("Error: {}", e);
+                }
+                outcomes.push(Outcome {
+                    year,
+                    day,
+                    title: solution.title(),
+                    source: None,
+                    expected: [None, None],
+                    result: Err(Failure::Input(e)),
+                });
+            }
+        }
+    }
+
+    let results: Vec<Result<Solved, Failure>> = if schedule.parallel {
+        let start = Instant::now();
+        let pending: Vec<_> = inputs.iter().map(|(solution, input, _, _)| spawn(*solution, input)).collect();
+        pending.into_iter().map(|pending| wait(pending, start, schedule.limit)).collect()
+    } else {
+        inputs.iter().map(|(solution, input, _, _)| solve_within(*solution, input, schedule.limit)).collect()
+    };
+
+    outcomes.extend(inputs.into_iter().zip(results).map(|((solution, _, source, expected), result)| Outcome {
+        year,
+        day: solution.day(),
+        title: solution.title(),
+        source,
+        expected,
+        result,
+    }));
+    outcomes.sort_by_key(|o| o.day);
+    outcomes
+}
+
+/// Runs several days of a year and prints a summary table of their answers and timings.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `days` - The days to run, or `None` to run every registered day of the year.
+/// * `schedule` - How the days are run.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `i32` - The exit code, non-zero if an input could not be retrieved, a day timed out or panicked, or an
+///   answer differs from the one the ledger expects.
+pub async fn run(year: i32, days: Option<Vec<i32>>, schedule: Schedule, context: &Context) -> i32 {
+    let outcomes = execute(year, days, schedule, false, context).await;
+    if outcomes.is_empty() {
+        return 3;
+    }
+
+    let mut table = Table::new(&["Day", "Title", "Part 1", "Part 2", "Parse", "Part 1 Time", "Part 2 Time", "Total"]);
+    let mut exit_code = 0;
+    let mut solved = vec![];
+    for outcome in &outcomes {
+        let result = match &outcome.result {
+            Ok(result) => result,
+            // days without a solution have already been reported and are not an error
+            Err(Failure::NotImplemented) => continue,
+            Err(Failure::Input(_)) => {
+                exit_code = 5;
+                continue;
+            }
+            Err(failure @ (Failure::TimedOut(_) | Failure::Panicked(_))) => {
+                exit_code = 5;
+                let mut row = vec![outcome.day.to_string(), outcome.title.to_string(), failure.to_string()];
+                row.resize(8, "".to_string());
+                table.add_row(row);
+                continue;
+            }
+        };
+
+        let cell = |part: i32, answer: &Answer| match &outcome.expected[part as usize - 1] {
+            Some(expected) if outcome.differs(part) => format!("{} (expected {})", answer, expected),
+            _ => answer.to_string(),
+        };
+        if outcome.differs(1) || outcome.differs(2) {
+            exit_code = exit_code.max(4);
+        }
+
+        table.add_row(vec![
+            outcome.day.to_string(),
+            outcome.title.to_string(),
+            cell(1, &result.part1.0),
+            cell(2, &result.part2.0),
+            format!("{:?}", result.parse),
+            format!("{:?}", result.part1.1),
+            format!("{:?}", result.part2.1),
+            format!("{:?}", result.total()),
+        ]);
+        solved.push(result);
+    }
+
+    let sum = |f: fn(&Solved) -> Duration| solved.iter().map(|s| f(s)).sum::<Duration>();
+    table.add_row(vec![
+        "".to_string(),
+        "Total".to_string(),
+        "".to_string(),
+        "".to_string(),
+        format!("{:?}", sum(|s| s.parse)),
+        format!("{:?}", sum(|s| s.part1.1)),
+        format!("{:?}", sum(|s| s.part2.1)),
+        format!("{:?}", sum(|s| s.total())),
+    ]);
+
+    println!();
+    table.print();
+
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::Solution;
+
+    struct Slow;
+
+    impl Solution for Slow {
+        type Input = u64;
+        type Part1 = u64;
+        type Part2 = u64;
+
+        fn year(&self) -> i32 {
+            2015
+        }
+
+        fn day(&self) -> i32 {
+            1
+        }
+
+        fn title(&self) -> &'static str {
+            "Slow"
+        }
+
+        fn parse(&self, input: &Input) -> u64 {
+            input.raw().trim().parse().unwrap()
+        }
+
+        fn part1(&self, millis: &u64) -> u64 {
+            std::thread::sleep(Duration::from_millis(*millis));
+            *millis
+        }
+
+        fn part2(&self, millis: &u64) -> u64 {
+            *millis
+        }
+    }
+
+    #[test]
+    fn days_taking_longer_than_the_limit_time_out() {
+        let limit = Duration::from_millis(50);
+        assert!(matches!(solve_within(&Slow, &Input::from("500"), Some(limit)), Err(Failure::TimedOut(l)) if l == limit));
+
+        let solved = solve_within(&Slow, &Input::from("1"), Some(Duration::from_secs(5)));
+        assert!(matches!(solved, Ok(solved) if solved.part1.0 == 1u64));
+        assert!(solve_within(&Slow, &Input::from("1"), None).is_ok());
+    }
+
+    #[test]
+    fn panicking_days_are_failures() {
+        let result = solve_within(&Slow, &Input::from("soon"), Some(Duration::from_secs(5)));
+        assert!(matches!(result, Err(Failure::Panicked(message)) if message.contains("ParseIntError")));
+        assert!(matches!(solve_within(&Slow, &Input::from("soon"), None), Err(Failure::Panicked(_))));
+    }
+
+    #[test]
+    fn answers_differing_from_the_ledger_are_marked() {
+        let outcome = Outcome {
+            year: 2015,
+            day: 1,
+            title: "Slow",
+            source: None,
+            expected: [Some(Answer::Number(2)), Some(Answer::Number(1))],
+            result: solve_within(&Slow, &Input::from("1"), None),
+        };
+        assert!(outcome.differs(1));
+        assert!(!outcome.differs(2));
+    }
+
+    #[test]
+    fn missing_years_name_the_implemented_years() {
+        let years = aoc::registry::years().iter().map(|y| y.to_string()).collect::<Vec<_>>().join(", ");
+        assert!(years.contains("2024"));
+        assert_eq!(not_implemented(1999, Some(1)), format!("1999 Day 1 not implemented, there are solutions for {}", years));
+        assert_eq!(not_implemented(1999, None), format!("No day of 1999 is implemented, there are solutions for {}", years));
+        assert_eq!(not_implemented(2024, Some(26)), "2024 Day 26 not implemented");
+    }
+
+    #[test]
+    fn days_and_ranges_are_expanded() {
+        assert_eq!(parse_days("1-3,13"), Ok(vec![1, 2, 3, 13]));
+    }
+
+    #[test]
+    fn days_are_ordered_without_duplicates() {
+        assert_eq!(parse_days("5, 2-3,3"), Ok(vec![2, 3, 5]));
+    }
+
+    #[test]
+    fn invalid_days_are_rejected() {
+        assert!(parse_days("1-x").is_err());
+        assert!(parse_days("10-1").is_err());
+        assert!(parse_days("0-3").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("").is_err());
+    }
+}
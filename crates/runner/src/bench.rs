@@ -0,0 +1,330 @@
+use crate::batch::{get_input, solve_within, Failure, Solved};
+use crate::table::Table;
+use aoc::{Context, DynSolution, Input};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// The name of the file in the profile directory holding the results of the last benchmark of each day.
+const RESULTS_FILE: &str = "bench.json";
+
+/// How many samples are taken from each phase.
+pub enum Limit {
+    /// Take a fixed number of samples.
+    Iterations(usize),
+    /// Take samples until the time budget has been spent, always taking at least one.
+    Budget(Duration),
+}
+
+/// Summary statistics of the samples taken from one phase, in nanoseconds.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Stats {
+    pub samples: usize,
+    pub min: f64,
+    pub median: f64,
+    pub mean: f64,
+    pub p95: f64,
+    pub stddev: f64,
+}
+
+impl Stats {
+    /// Summarizes a set of samples.
+    ///
+    /// # Panics
+    ///
+    /// If there are no samples.
+    pub fn new(samples: &[Duration]) -> Stats {
+        let mut nanos: Vec<f64> = samples.iter().map(|s| s.as_nanos() as f64).collect();
+        nanos.sort_by(|a, b| a.total_cmp(b));
+
+        let count = nanos.len();
+        let mean = nanos.iter().sum::<f64>() / count as f64;
+        let median = if count.is_multiple_of(2) {
+            (nanos[count / 2 - 1] + nanos[count / 2]) / 2.0
+        } else {
+            nanos[count / 2]
+        };
+        // nearest rank
+        let p95 = nanos[((count as f64 * 0.95).ceil() as usize).clamp(1, count) - 1];
+        let stddev = (nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / count as f64).sqrt();
+
+        Stats {
+            samples: count,
+            min: nanos[0],
+            median,
+            mean,
+            p95,
+            stddev,
+        }
+    }
+}
+
+/// The result of benchmarking one day.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Record {
+    /// Seconds since the unix epoch when the benchmark was taken.
+    pub timestamp: u64,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// Solves a day repeatedly, giving up on the first run which times out or panics.
+fn sample(warmup: usize, limit: &Limit, mut f: impl FnMut() -> Result<Solved, Failure>) -> Result<Vec<Solved>, Failure> {
+    for _ in 0..warmup {
+        f()?;
+    }
+
+    let mut samples = vec![];
+    let start_time = Instant::now();
+    loop {
+        samples.push(f()?);
+
+        let done = match limit {
+            Limit::Iterations(n) => samples.len() >= *n,
+            Limit::Budget(budget) => start_time.elapsed() >= *budget,
+        };
+        if done {
+            break;
+        }
+    }
+
+    Ok(samples)
+}
+
+/// Benchmarks a day with every run guarded by the time limit so a slow or panicking day cannot stop the others.
+fn measure(solution: &'static dyn DynSolution, input: &Input, warmup: usize, limit: &Limit, timeout: Option<Duration>) -> Result<Record, Failure> {
+    let samples = sample(warmup, limit, || solve_within(solution, input, timeout))?;
+    let stats = |phase: fn(&Solved) -> Duration| Stats::new(&samples.iter().map(phase).collect::<Vec<_>>());
+
+    Ok(Record {
+        timestamp: SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0),
+        parse: stats(|s| s.parse),
+        part1: stats(|s| s.part1.1),
+        part2: stats(|s| s.part2.1),
+    })
+}
+
+fn results_path(context: &Context) -> PathBuf {
+    context.profile_dir().join(RESULTS_FILE)
+}
+
+fn load(path: &Path) -> Result<BTreeMap<String, Record>, String> {
+    if !path.exists() {
+        return Ok(BTreeMap::new());
+    }
+
+    let body = fs::read_to_string(path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&body).map_err(|e| format!("Unable to read {}: {}", path.display(), e))
+}
+
+fn save(path: &Path, results: &BTreeMap<String, Record>) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let body = serde_json::to_string_pretty(results).map_err(|e| e.to_string())?;
+    fs::write(path, body).map_err(|e| e.to_string())
+}
+
+/// Describes the change in the median from a previous run.
+///
+/// # Returns
+///
+/// * `(String, bool)` - The relative change and whether it exceeds the threshold.
+fn compare(current: &Stats, previous: Option<&Stats>, threshold: f64) -> (String, bool) {
+    match previous {
+        Some(previous) if previous.median > 0.0 => {
+            let change = (current.median - previous.median) / previous.median * 100.0;
+            let regressed = change > threshold;
+            let text = format!("{:+.1}%{}", change, if regressed { " REGRESSION" } else { "" });
+            (text, regressed)
+        }
+        _ => ("".to_string(), false),
+    }
+}
+
+fn format_nanos(nanos: f64) -> String {
+    format!("{:?}", Duration::from_nanos(nanos.round() as u64))
+}
+
+/// Benchmarks the selected solutions and compares them with the previous results.
+///
+/// # Arguments
+///
+/// * `solutions` - The solutions to benchmark.
+/// * `warmup` - The number of unmeasured runs of each phase before sampling.
+/// * `limit` - How many samples to take from each phase.
+/// * `threshold` - The percentage by which the median may grow before it is flagged as a regression.
+/// * `timeout` - The longest a single run of a day may take, or `None` to wait for as long as it takes.
+/// * `keep` - Compare with the saved results without replacing them.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `i32` - The exit code, non-zero if an input could not be retrieved, a day timed out or panicked, or a
+///   regression was found.
+pub async fn run(solutions: Vec<&'static dyn DynSolution>, warmup: usize, limit: Limit, threshold: f64, timeout: Option<Duration>, keep: bool, context: &Context) -> i32 {
+    let path = results_path(context);
+    let mut results = match load(&path) {
+        Ok(results) => results,
+        Err(e) => {
+            println!("Error: {}", e);
+            return 5;
+        }
+    };
+
+    let mut table = Table::new(&["Year", "Day", "Phase", "Samples", "Min", "Median", "Mean", "P95", "Std Dev", "Change"]);
+    let mut exit_code = 0;
+
+    for solution in solutions {
+        let (year, day) = (solution.year(), solution.day());
+        let input = match get_input(year, day, context).await {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Error: {}", e);
+                exit_code = 5;
+                continue;
+            }
+        };
+
+        println!("Benchmarking {} Day {}: {}", year, day, solution.title());
+        let record = match measure(solution, &input, warmup, &limit, timeout) {
+            Ok(record) => record,
+            Err(failure) => {
+                println!("Error: {} Day {} {}", year, day, failure);
+                let mut row = vec![year.to_string(), day.to_string(), failure.to_string()];
+                row.resize(10, "".to_string());
+                table.add_row(row);
+                exit_code = 5;
+                continue;
+            }
+        };
+        let key = format!("{}.day{}", year, day);
+        let previous = results.get(&key);
+
+        for (phase, current, previous) in [
+            ("Parse", &record.parse, previous.map(|p| &p.parse)),
+            ("Part 1", &record.part1, previous.map(|p| &p.part1)),
+            ("Part 2", &record.part2, previous.map(|p| &p.part2)),
+        ] {
+            let (change, regressed) = compare(current, previous, threshold);
+            if regressed {
+                exit_code = exit_code.max(7);
+            }
+
+            table.add_row(vec![
+                year.to_string(),
+                day.to_string(),
+                phase.to_string(),
+                current.samples.to_string(),
+                format_nanos(current.min),
+                format_nanos(current.median),
+                format_nanos(current.mean),
+                format_nanos(current.p95),
+                format_nanos(current.stddev),
+                change,
+            ]);
+        }
+
+        results.insert(key, record);
+    }
+
+    println!();
+    table.print();
+
+    if keep {
+        return exit_code;
+    }
+
+    if let Err(e) = save(&path, &results) {
+        println!("Error: {}", e);
+        return 5;
+    }
+
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::Answer;
+
+    fn stats(millis: &[u64]) -> Stats {
+        Stats::new(&millis.iter().map(|m| Duration::from_millis(*m)).collect::<Vec<_>>())
+    }
+
+    #[test]
+    fn stats_of_odd_samples() {
+        let result = stats(&[5, 1, 3]);
+        assert_eq!(result.samples, 3);
+        assert_eq!(result.min, 1e6);
+        assert_eq!(result.median, 3e6);
+        assert_eq!(result.mean, 3e6);
+        assert_eq!(result.p95, 5e6);
+    }
+
+    #[test]
+    fn stats_of_even_samples() {
+        let result = stats(&[2, 4, 4, 4, 5, 5, 7, 9]);
+        assert_eq!(result.median, 4.5e6);
+        assert_eq!(result.mean, 5e6);
+        assert_eq!(result.stddev, 2e6);
+    }
+
+    #[test]
+    fn p95_uses_nearest_rank() {
+        let samples: Vec<u64> = (1..=100).collect();
+        assert_eq!(stats(&samples).p95, 95e6);
+    }
+
+    #[test]
+    fn regression_is_flagged_over_threshold() {
+        let previous = stats(&[10]);
+        assert_eq!(compare(&stats(&[12]), Some(&previous), 10.0), ("+20.0%".to_string() + " REGRESSION", true));
+        assert_eq!(compare(&stats(&[9]), Some(&previous), 10.0), ("-10.0%".to_string(), false));
+        assert_eq!(compare(&stats(&[9]), None, 10.0), ("".to_string(), false));
+    }
+
+    fn solved() -> Solved {
+        Solved {
+            parse: Duration::ZERO,
+            part1: (Answer::Unsolved, Duration::ZERO),
+            part2: (Answer::Unsolved, Duration::ZERO),
+        }
+    }
+
+    #[test]
+    fn budget_takes_at_least_one_sample() {
+        let result = sample(0, &Limit::Budget(Duration::ZERO), || Ok(solved()));
+        assert_eq!(result.ok().map(|samples| samples.len()), Some(1));
+    }
+
+    #[test]
+    fn iterations_are_honored() {
+        let mut calls = 0;
+        let result = sample(2, &Limit::Iterations(5), || {
+            calls += 1;
+            Ok(solved())
+        });
+        assert_eq!(result.ok().map(|samples| samples.len()), Some(5));
+        assert_eq!(calls, 7);
+    }
+
+    #[test]
+    fn failing_runs_stop_sampling() {
+        let mut calls = 0;
+        let result = sample(0, &Limit::Iterations(5), || {
+            calls += 1;
+            if calls == 3 {
+                Err(Failure::Panicked("boom".to_string()))
+            } else {
+                Ok(solved())
+            }
+        });
+        assert!(matches!(result, Err(Failure::Panicked(message)) if message == "boom"));
+        assert_eq!(calls, 3);
+    }
+}
@@ -0,0 +1,125 @@
+use crate::table::Table;
+use aoc::cache::{Entry, Integrity};
+use aoc::Context;
+
+/// Lists the cached inputs of the selected year, or of every year.
+///
+/// # Returns
+///
+/// * `i32` - The exit code.
+pub fn list(year: Option<i32>, context: &Context) -> i32 {
+    let Some(entries) = load_entries(year, None, context) else {
+        return 5;
+    };
+
+    let mut table = Table::new(&["Year", "Day", "Bytes", "Fetched", "Status", "SHA-256", "Integrity"]);
+    for entry in &entries {
+        let mut row = vec![entry.year.to_string(), entry.day.to_string()];
+        match &entry.metadata {
+            Some(metadata) => row.extend([
+                metadata.length.to_string(),
+                aoc::cache::format_fetched_at(metadata),
+                metadata.status.to_string(),
+                metadata.sha256.chars().take(12).collect(),
+            ]),
+            None => row.extend(["".to_string(), "".to_string(), "".to_string(), "".to_string()]),
+        }
+        row.push(entry.integrity.to_string());
+        table.add_row(row);
+    }
+
+    table.print();
+    0
+}
+
+/// Checks the cached inputs against their metadata.
+///
+/// # Returns
+///
+/// * `i32` - The exit code, non-zero if any input cannot be trusted.
+pub fn verify(year: Option<i32>, day: Option<i32>, context: &Context) -> i32 {
+    let Some(entries) = load_entries(year, day, context) else {
+        return 5;
+    };
+
+    let mut invalid = 0;
+    for entry in &entries {
+        println!("{} Day {}: {}", entry.year, entry.day, entry.integrity);
+        if matches!(entry.integrity, Integrity::Invalid(_)) {
+            invalid += 1;
+        }
+    }
+
+    if invalid > 0 {
+        println!("{} of {} cached inputs are invalid, refetch them with `cache refetch`", invalid, entries.len());
+        4
+    } else {
+        0
+    }
+}
+
+/// Removes the cached inputs of a year, or of a single day.
+///
+/// # Returns
+///
+/// * `i32` - The exit code.
+pub fn purge(year: i32, day: Option<i32>, context: &Context) -> i32 {
+    let Some(entries) = load_entries(Some(year), day, context) else {
+        return 5;
+    };
+    if entries.is_empty() {
+        println!("Nothing cached to purge");
+    }
+
+    for entry in entries {
+        match aoc::cache::purge(&entry.year, &entry.day, context) {
+            Ok(_) => println!("{} Day {}: purged", entry.year, entry.day),
+            Err(e) => {
+                println!("Error: {}", e);
+                return 5;
+            }
+        }
+    }
+
+    0
+}
+
+/// Downloads the inputs of a year again, or a single day, replacing the cached copies.
+///
+/// Without a day only the inputs already in the cache are downloaded.
+///
+/// # Returns
+///
+/// * `i32` - The exit code.
+pub async fn refetch(year: i32, day: Option<i32>, context: &Context) -> i32 {
+    let days = match day {
+        Some(day) => vec![day],
+        None => match load_entries(Some(year), None, context) {
+            Some(entries) => entries.iter().map(|e| e.day).collect(),
+            None => return 5,
+        },
+    };
+
+    for day in days {
+        match aoc::download_input(&year, &day, context).await {
+            Ok(body) => println!("{} Day {}: downloaded {} bytes", year, day, body.len()),
+            Err(e) => {
+                println!("{} Day {}: {}", year, day, e);
+                return 5;
+            }
+        }
+    }
+
+    0
+}
+
+/// Loads the cached inputs matching the selection, explaining when the cache cannot be read.
+fn load_entries(year: Option<i32>, day: Option<i32>, context: &Context) -> Option<Vec<Entry>> {
+    match aoc::cache::entries(context) {
+        Ok(entries) => Some(entries.into_iter().filter(|e| year.is_none_or(|y| y == e.year) && day.is_none_or(|d| d == e.day)).collect()),
+        Err(e) => {
+            println!("Error: {}", e);
+            None
+        }
+    }
+}
@@ -1,90 +1,704 @@
-use aoc;
-use getopts::Options;
+use aoc::http::{HttpClient, HttpConfig};
+use aoc::ledger::Ledger;
+use aoc::session::SessionError;
+use aoc::unlock::SystemClock;
+use aoc::{Answer, Context, DynSolution, Input};
+use batch::{Days, Schedule};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use output::Format;
+use std::io;
+use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::Arc;
+use std::time::Duration;
 use url::Url;
 
+mod batch;
+mod bench;
+mod cache;
+mod output;
+mod scaffold;
+mod table;
+mod verify;
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+/// The first year Advent of Code was held.
+const FIRST_YEAR: i64 = aoc::FIRST_YEAR as i64;
+
+#[derive(Parser)]
+#[command(about = "Runs and submits Advent of Code solutions")]
+struct Cli {
+    /// The session ID to use instead of the AOC_SESSION environment variable or a session file
+    #[arg(long, global = true)]
+    session: Option<String>,
+    /// The User-Agent sent to the site, ideally including how to contact you
+    #[arg(long, global = true)]
+    user_agent: Option<String>,
+    /// The named account to use, keeping its session, inputs and answers apart from other accounts
+    #[arg(long, global = true, value_parser = parse_profile)]
+    profile: Option<String>,
+    /// The address of the site, such as a mirror or a local server
+    #[arg(long, global = true, env = "AOC_URL", default_value = aoc::DEFAULT_URL, value_parser = parse_url)]
+    url: Url,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions for one or more days
+    Run(RunArgs),
+    /// Download the input for a day into the cache
+    Fetch {
+        #[command(flatten)]
+        puzzle: PuzzleArgs,
+        /// Wait for the puzzle to unlock when it has not been released yet
+        #[arg(long)]
+        wait: bool,
+    },
+    /// Print the description of a puzzle, downloading it when it is not cached
+    Read {
+        #[command(flatten)]
+        puzzle: PuzzleArgs,
+        /// Download the description even if it is cached
+        #[arg(long)]
+        refresh: bool,
+    },
+    /// Extract the examples of a puzzle description into test fixtures for its day
+    Samples {
+        #[command(flatten)]
+        puzzle: PuzzleArgs,
+        /// The directory to write the fixtures to instead of the src/samples directory of the day
+        #[arg(long)]
+        out: Option<PathBuf>,
+    },
+    /// Generate the crate for a new day and add it to the runner
+    New {
+        #[command(flatten)]
+        puzzle: PuzzleArgs,
+        /// Download the input and the description to fill in the title and the sample
+        #[arg(long)]
+        fetch: bool,
+    },
+    /// Run a day and submit the answer to one of its parts
+    Submit {
+        #[command(flatten)]
+        puzzle: PuzzleArgs,
+        /// The part of the puzzle to submit
+        #[arg(short, long, value_parser = clap::value_parser!(i32).range(1..=2))]
+        part: i32,
+    },
+    /// Measure how long the solutions take over many runs and compare with the previous results
+    Bench(BenchArgs),
+    /// Check the solutions against the answers accepted by the site or recorded as expected
+    Verify {
+        /// Only verify the specified year
+        #[arg(short, long, value_parser = clap::value_parser!(i32).range(FIRST_YEAR..))]
+        year: Option<i32>,
+        /// Only verify the specified day
+        #[arg(short, long, value_parser = clap::value_parser!(i32).range(1..=25))]
+        day: Option<i32>,
+        /// Record the current answers as expected for the parts without an expected answer
+        #[arg(long)]
+        record: bool,
+        /// The seconds a day may take before it is reported as timed out, 0 for no limit. A day
+        /// which times out keeps running in the background until every day has been verified
+        #[arg(long, default_value = "60", value_parser = parse_timeout)]
+        timeout: Duration,
+    },
+    /// Inspect and maintain the cached inputs
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommand,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommand {
+    /// List the cached inputs with when and how they were fetched
+    List {
+        /// Only list the specified year
+        #[arg(short, long, value_parser = clap::value_parser!(i32).range(FIRST_YEAR..))]
+        year: Option<i32>,
+    },
+    /// Check the cached inputs against their recorded length and digest
+    Verify {
+        /// Only verify the specified year
+        #[arg(short, long, value_parser = clap::value_parser!(i32).range(FIRST_YEAR..))]
+        year: Option<i32>,
+        /// Only verify the specified day
+        #[arg(short, long, value_parser = clap::value_parser!(i32).range(1..=25))]
+        day: Option<i32>,
+    },
+    /// Remove the cached inputs of a year or day
+    Purge(CacheArgs),
+    /// Download the cached inputs of a year or day again
+    Refetch(CacheArgs),
+}
+
+#[derive(Args)]
+struct CacheArgs {
+    /// The year of the advent of code
+    #[arg(short, long, value_parser = clap::value_parser!(i32).range(FIRST_YEAR..))]
+    year: i32,
+    /// Only the specified day instead of every cached day of the year
+    #[arg(short, long, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: Option<i32>,
+}
+
+#[derive(Args)]
+struct PuzzleArgs {
+    /// The year of the advent of code
+    #[arg(short, long, value_parser = clap::value_parser!(i32).range(FIRST_YEAR..))]
+    year: i32,
+    /// The day of the advent of code
+    #[arg(short, long, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: i32,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "days", "all"])))]
+struct SelectionArgs {
+    /// The year of the advent of code
+    #[arg(short, long, value_parser = clap::value_parser!(i32).range(FIRST_YEAR..))]
+    year: i32,
+    /// The day of the advent of code
+    #[arg(short, long, value_parser = clap::value_parser!(i32).range(1..=25))]
+    day: Option<i32>,
+    /// The days of the advent of code, e.g. 1-10,13
+    #[arg(long)]
+    days: Option<Days>,
+    /// Every implemented day of the year
+    #[arg(long)]
+    all: bool,
+}
+
+impl SelectionArgs {
+    /// The selected days, or `None` when every day of the year is selected.
+    fn days(&self) -> Option<Vec<i32>> {
+        match (self.day, &self.days) {
+            (Some(day), _) => Some(vec![day]),
+            (None, days) => days.as_ref().map(|d| d.0.clone()),
+        }
+    }
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[command(flatten)]
+    selection: SelectionArgs,
+    /// Run the days on separate threads when running several days
+    #[arg(long)]
+    parallel: bool,
+    /// Wait for the day to unlock when it has not been released yet
+    #[arg(long, requires = "day")]
+    wait: bool,
+    /// The known answer to part 1
+    #[arg(long, requires = "day", value_parser = <Answer as std::str::FromStr>::from_str)]
+    expect1: Option<Answer>,
+    /// The known answer to part 2
+    #[arg(long, requires = "day", value_parser = <Answer as std::str::FromStr>::from_str)]
+    expect2: Option<Answer>,
+    /// How the results are written
+    #[arg(short, long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// The seconds a day may take when running several days before it is reported as timed out, 0 for no limit.
+    /// A day which times out keeps running in the background until every day has been run
+    #[arg(long, default_value = "60", value_parser = parse_timeout)]
+    timeout: Duration,
+}
+
+impl RunArgs {
+    /// How the selected days are run.
+    fn schedule(&self) -> Schedule {
+        Schedule {
+            parallel: self.parallel,
+            limit: time_limit(self.timeout),
+        }
+    }
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    selection: SelectionArgs,
+    /// The number of measured runs of each phase
+    #[arg(short, long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "budget")]
+    iterations: u64,
+    /// Measure each phase for this many seconds instead of a fixed number of runs
+    #[arg(short, long)]
+    budget: Option<f64>,
+    /// The number of unmeasured runs of each phase before measuring
+    #[arg(short, long, default_value_t = 3)]
+    warmup: usize,
+    /// The percentage the median may grow from the previous run before it is flagged as a regression
+    #[arg(short, long, default_value_t = 10.0)]
+    threshold: f64,
+    /// The seconds a single run of a day may take before the day is reported as timed out, 0 for no limit
+    #[arg(long, default_value = "60", value_parser = parse_timeout)]
+    timeout: Duration,
+    /// Compare with the saved results without replacing them
+    #[arg(long)]
+    keep: bool,
+}
+
 #[tokio::main]
 async fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    let program = args[0].clone();
+    let cli = Cli::parse();
+    let options = SiteOptions {
+        session: cli.session.as_deref(),
+        profile: cli.profile.as_deref(),
+        user_agent: cli.user_agent.as_deref(),
+        url: &cli.url,
+    };
 
-    let mut opts = Options::new();
-    opts.optflag("", "profile", "");
-    opts.optflag("", "manifest-path", "");
-    opts.optflag("h", "help", "print this help menu");
-    opts.optflag("y", "year", "The year of the advent of code");
-    opts.optflag("d", "day", "The day of the advent of code to run");
+    let exit_code = match cli.command {
+        Command::Run(args) => {
+            let year = args.selection.year;
+            let context = prepare_context(&options, year, args.selection.days()).await;
+            if let (true, Some(day)) = (args.wait, args.selection.day) {
+                wait_for_unlock(year, day, &context).await;
+            }
+            match (args.format, args.selection.day) {
+                (Format::Text, Some(day)) => run_day(year, day, args.expect1, args.expect2, &context).await,
+                (Format::Text, None) => batch::run(year, args.selection.days(), args.schedule(), &context).await,
+                (format, _) => run_structured(args, format, &context).await,
+            }
+        }
+        Command::Fetch { puzzle, wait } => {
+            let context = prepare_context(&options, puzzle.year, Some(vec![puzzle.day])).await;
+            if wait {
+                wait_for_unlock(puzzle.year, puzzle.day, &context).await;
+            }
+            fetch(puzzle.year, puzzle.day, &context).await
+        }
+        Command::Read { puzzle, refresh } => read(puzzle.year, puzzle.day, refresh, &build_context(&options, false)).await,
+        Command::Samples { puzzle, out } => {
+            let out = out.unwrap_or_else(|| PathBuf::from(format!("crates/year{}/day{}/src/samples", puzzle.year, puzzle.day)));
+            samples(puzzle.year, puzzle.day, &out, &build_context(&options, false)).await
+        }
+        Command::New { puzzle, fetch } => {
+            let context = build_context(&options, fetch);
+            if fetch {
+                validate_session(&options, &context).await;
+            }
+            scaffold::new_day(puzzle.year, puzzle.day, fetch, &context).await
+        }
+        Command::Submit { puzzle, part } => {
+            let context = build_context(&options, true);
+            validate_session(&options, &context).await;
+            submit(puzzle.year, puzzle.day, part, &context).await
+        }
+        Command::Bench(args) => {
+            let limit = match args.budget {
+                Some(seconds) if seconds.is_finite() && seconds > 0.0 => bench::Limit::Budget(Duration::from_secs_f64(seconds)),
+                Some(seconds) => {
+                    println!("{} is not a valid time budget", seconds);
+                    process::exit(1);
+                }
+                None => bench::Limit::Iterations(args.iterations as usize),
+            };
+            let context = prepare_context(&options, args.selection.year, args.selection.days()).await;
+            let solutions = batch::select(args.selection.year, args.selection.days());
+            bench::run(solutions, args.warmup, limit, args.threshold, time_limit(args.timeout), args.keep, &context).await
+        }
+        Command::Verify { year, day, record, timeout } => verify::run(year, day, record, time_limit(timeout), &build_context(&options, false)),
+        Command::Cache { command } => match command {
+            CacheCommand::List { year } => cache::list(year, &build_context(&options, false)),
+            CacheCommand::Verify { year, day } => cache::verify(year, day, &build_context(&options, false)),
+            CacheCommand::Purge(args) => cache::purge(args.year, args.day, &build_context(&options, false)),
+            CacheCommand::Refetch(args) => {
+                let context = build_context(&options, true);
+                validate_session(&options, &context).await;
+                cache::refetch(args.year, args.day, &context).await
+            }
+        },
+    };
+
+    process::exit(exit_code);
+}
+
+/// The account and connection options shared by every command.
+struct SiteOptions<'a> {
+    /// The session ID passed on the command line.
+    session: Option<&'a str>,
+    profile: Option<&'a str>,
+    user_agent: Option<&'a str>,
+    url: &'a Url,
+}
+
+fn parse_profile(profile: &str) -> Result<String, String> {
+    aoc::session::validate_profile(profile).map(|_| profile.to_string())
+}
+
+fn parse_url(url: &str) -> Result<Url, String> {
+    match Url::parse(url) {
+        Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => Ok(parsed),
+        Ok(_) => Err(format!("{} is not an http or https address", url)),
+        Err(e) => Err(format!("{} is not a valid address: {}", url, e)),
+    }
+}
+
+fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    match seconds.parse::<f64>() {
+        Ok(seconds) if seconds.is_finite() && seconds >= 0.0 => Ok(Duration::from_secs_f64(seconds)),
+        _ => Err(format!("{} is not a valid number of seconds", seconds)),
+    }
+}
+
+/// The time limit for a day, where no time at all means there is no limit.
+fn time_limit(timeout: Duration) -> Option<Duration> {
+    Some(timeout).filter(|timeout| !timeout.is_zero())
+}
+
+/// Builds the context used to interact with the site from the current directory.
+///
+/// # Arguments
+///
+/// * `options` - The account and connection options passed on the command line.
+/// * `require_session` - Exit when no session ID is available.
+fn build_context(options: &SiteOptions, require_session: bool) -> Context {
+    let root = match std::env::current_dir() {
+        Ok(root) => root,
+        Err(e) => {
+            println!("Unable to determine the current directory: {}", e);
+            process::exit(5);
+        }
+    };
 
-    let matches = match opts.parse(&args[1..]) {
-        Ok(m) => m,
-        Err(f) => {
-            println!("{}", f.to_string());
-            process::exit(1);
+    let session_id = match aoc::session::resolve(options.session, options.profile, &root) {
+        Some(session) => {
+            // reported on stderr so it does not mix with structured output
+            eprintln!("Using session from {}", session.source);
+            session.id
         }
+        None if !require_session => String::new(),
+        None => exit_without_session(options),
     };
 
-    if matches.opt_present("h") {
-        let brief = format!("Usage: {} [options]", program);
-        print!("{}", opts.usage(&brief));
-        process::exit(0);
+    let data_dir = root.join("Data");
+    let http = HttpClient::new(HttpConfig {
+        user_agent: options.user_agent.unwrap_or(aoc::http::DEFAULT_USER_AGENT).to_string(),
+        // every profile shares the interval as the site sees a single client
+        state_file: Some(data_dir.join(".last_request")),
+        ..HttpConfig::default()
+    });
+    let http = match http {
+        Ok(http) => http,
+        Err(e) => {
+            println!("Unable to build the HTTP client: {}", e);
+            process::exit(5);
+        }
+    };
+
+    Context {
+        url: options.url.clone(),
+        data_dir,
+        session_id,
+        profile: options.profile.map(|p| p.to_string()),
+        clock: Arc::new(SystemClock),
+        http,
     }
+}
 
-    let session_id = match aoc::session::get_session_id(&std::env::current_dir().unwrap()) {
-        Some(id) => id,
-        None => {
-            println!("Session ID not found. Please create a .session file in the current directory with your session ID.");
-            process::exit(2);
+/// Builds the context for a command which may download inputs, checking the session only when
+/// one of the selected inputs has not been cached yet.
+///
+/// A single day cannot be solved without its input, so a missing or rejected session ends the run
+/// with guidance. Several days are still solved from the cache: the session is dropped so the
+/// uncached days are reported as missing input without contacting the site.
+///
+/// # Arguments
+///
+/// * `options` - The account and connection options passed on the command line.
+/// * `year` - The year of the event.
+/// * `days` - The selected days, or `None` for every implemented day of the year.
+async fn prepare_context(options: &SiteOptions<'_>, year: i32, days: Option<Vec<i32>>) -> Context {
+    let mut context = build_context(options, false);
+    let single = matches!(&days, Some(days) if days.len() == 1);
+
+    let days = days.unwrap_or_else(|| aoc::registry::solutions().iter().filter(|s| s.year() == year).map(|s| s.day()).collect());
+    let uncached: Vec<i32> = days
+        .into_iter()
+        .filter(|day| aoc::registry::find(year, *day).is_some())
+        .filter(|day| !matches!(aoc::get_input_from_cache(&year, day, &context), Ok(Some(_))))
+        .collect();
+    if uncached.is_empty() {
+        return context;
+    }
+
+    if single {
+        if context.session_id.is_empty() {
+            exit_without_session(options);
+        }
+        validate_session(options, &context).await;
+        return context;
+    }
+
+    let reason = if context.session_id.is_empty() {
+        SessionError::Missing
+    } else {
+        match aoc::session::validate(&context).await {
+            Ok(()) => return context,
+            Err(e) => e,
         }
     };
+    // reported on stderr so it does not mix with structured output
+    let days = uncached.iter().map(|day| format!("Day {}", day)).collect::<Vec<_>>().join(", ");
+    eprintln!("{}, so the inputs for {} {} cannot be downloaded", reason, year, days);
+    context.session_id.clear();
+
+    context
+}
+
+/// Exits with guidance on where to provide the session ID.
+fn exit_without_session(options: &SiteOptions) -> ! {
+    println!("Session ID not found. Provide it with one of the following:");
+    print_session_sources(options);
+    process::exit(2);
+}
 
-    let year = match matches.opt_get::<i32>("y") {
-        Ok(Some(y)) => y,
-        _ => {
-            args[1].parse().unwrap()
+/// Checks the site accepts the session before anything is solved, exiting with guidance when it does not.
+async fn validate_session(options: &SiteOptions<'_>, context: &Context) {
+    match aoc::session::validate(context).await {
+        Ok(()) => {}
+        Err(SessionError::Request(e)) => {
+            println!("Unable to validate the session: {}", e);
+            process::exit(5);
         }
+        Err(e) => {
+            println!("{}.", e);
+            println!("Log in to {} and copy the value of the session cookie into one of the following:", context.url);
+            print_session_sources(options);
+            process::exit(2);
+        }
+    }
+}
+
+/// Counts down until a puzzle is released so its input can be fetched the moment it opens.
+async fn wait_for_unlock(year: i32, day: i32, context: &Context) {
+    let mut waited = false;
+    loop {
+        let remaining = aoc::unlock::time_until_unlock(year, day, context.clock.as_ref());
+        if remaining.is_zero() {
+            break;
+        }
+
+        // the countdown is written to stderr so it does not mix with structured output
+        let seconds = remaining.as_secs_f64().ceil() as u64;
+        eprint!("\r{} Day {} unlocks in {:02}:{:02}:{:02} ", year, day, seconds / 3600, seconds / 60 % 60, seconds % 60);
+        waited = true;
+        tokio::time::sleep(remaining.min(Duration::from_secs(1))).await;
+    }
+
+    if waited {
+        eprintln!();
+    }
+}
+
+/// Lists the places a session ID can be provided, in the order they are checked.
+fn print_session_sources(options: &SiteOptions) {
+    println!("  --session <SESSION>");
+    println!("  the {} environment variable", aoc::session::session_variable(options.profile));
+    println!("  a {} file in the current directory", aoc::session::session_file_name(options.profile));
+    if let Some(path) = aoc::session::config_file(options.profile) {
+        println!("  {}", path.display());
+    }
+}
+
+/// Finds the solution for a puzzle, explaining when there is none.
+fn find_solution(year: i32, day: i32) -> Option<&'static dyn DynSolution> {
+    let solution = aoc::registry::find(year, day);
+    if solution.is_none() {
+        println!("{}", batch::not_implemented(year, Some(day)));
+    }
+    solution
+}
+
+/// Loads the input for a puzzle, explaining when it cannot be retrieved.
+async fn load_input(year: i32, day: i32, context: &Context) -> Option<Input> {
+    match batch::get_input(year, day, context).await {
+        Ok(input) => Some(input),
+        Err(e) => {
+            println!("Error: {}", e);
+            None
+        }
+    }
+}
+
+/// Loads the ledger for a puzzle, explaining when it cannot be read.
+fn load_ledger(year: i32, day: i32, context: &Context) -> Option<Ledger> {
+    match Ledger::load(&year, &day, context) {
+        Ok(ledger) => Some(ledger),
+        Err(e) => {
+            println!("Error: {}", e);
+            None
+        }
+    }
+}
+
+async fn run_day(year: i32, day: i32, expect1: Option<Answer>, expect2: Option<Answer>, context: &Context) -> i32 {
+    let Some(solution) = find_solution(year, day) else {
+        return 3;
+    };
+    let Some(input) = load_input(year, day, context).await else {
+        return 5;
+    };
+    let Some(ledger) = load_ledger(year, day, context) else {
+        return 5;
+    };
+
+    println!("{} Day {}: {}", year, day, solution.title());
+    let (parsed, parse_time) = batch::time(|| solution.parse(&input));
+    let (part1, part1_time) = batch::time(|| solution.part1(&parsed));
+    let (part2, part2_time) = batch::time(|| solution.part2(&parsed));
+    println!("Parse time: {:?}", parse_time);
+    println!("Part 1 time: {:?}", part1_time);
+    println!("Part 2 time: {:?}", part2_time);
+    println!("Elapsed: {:?}", parse_time + part1_time + part2_time);
+
+    // answers accepted by the site or recorded as expected are checked unless told otherwise
+    let expected1 = expect1.or(ledger.expected(1).cloned());
+    let expected2 = expect2.or(ledger.expected(2).cloned());
+    let verified = [report(1, &part1, expected1), report(2, &part2, expected2)];
+
+    if verified.contains(&false) {
+        4
+    } else {
+        0
+    }
+}
+
+/// Runs the selected days and writes their results in a machine readable format.
+async fn run_structured(args: RunArgs, format: Format, context: &Context) -> i32 {
+    let year = args.selection.year;
+    let schedule = args.schedule();
+    // answers given on the command line only make sense for a single day, the ledger covers the rest
+    let overrides = match args.selection.day {
+        Some(_) => [args.expect1, args.expect2],
+        None => [None, None],
     };
 
-    let day = match matches.opt_get::<i32>("d") {
-        Ok(Some(d)) => d,
-        _ => {
-            args[2].parse().unwrap()
+    output::run(year, args.selection.days(), schedule, overrides, format, &mut io::stdout(), context).await
+}
+
+async fn fetch(year: i32, day: i32, context: &Context) -> i32 {
+    match load_input(year, day, context).await {
+        Some(input) => {
+            println!("{} Day {}: {} lines available", year, day, input.lines().len());
+            0
+        }
+        None => 5,
+    }
+}
+
+async fn read(year: i32, day: i32, refresh: bool, context: &Context) -> i32 {
+    match aoc::puzzle::get_description(&year, &day, refresh, context).await {
+        Ok((markdown, _)) => {
+            print!("{}", markdown);
+            0
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+            5
+        }
+    }
+}
+
+async fn samples(year: i32, day: i32, out: &Path, context: &Context) -> i32 {
+    let page = match aoc::puzzle::get_description(&year, &day, false, context).await {
+        Ok(_) => aoc::puzzle::get_page_from_cache(&year, &day, context),
+        Err(e) => Err(e),
+    };
+    let examples = match page {
+        Ok(Some(page)) => aoc::samples::extract(&page),
+        Ok(None) => vec![],
+        Err(e) => {
+            println!("Error: {}", e);
+            return 5;
         }
     };
 
+    if let Err(e) = aoc::samples::save(out, &examples) {
+        println!("Error: {}", e);
+        return 5;
+    }
+    for example in &examples {
+        let answers = (1..=2).map(|part| example.answer(part).map_or("-".to_string(), |a| a.to_string())).collect::<Vec<_>>();
+        println!("{}: {} lines, part 1 {}, part 2 {}", example.name, example.input.lines().len(), answers[0], answers[1]);
+    }
+    println!("{} examples written to {}", examples.len(), out.display());
+    0
+}
 
-    let context = aoc::Context {
-        url: Url::parse("https://adventofcode.com").unwrap(),
-        data_dir: std::env::current_dir().unwrap().join("Data"),
-        session_id,
+async fn submit(year: i32, day: i32, part: i32, context: &Context) -> i32 {
+    let Some(solution) = find_solution(year, day) else {
+        return 3;
+    };
+    let Some(input) = load_input(year, day, context).await else {
+        return 5;
+    };
+    let Some(mut ledger) = load_ledger(year, day, context) else {
+        return 5;
     };
-    let input = aoc::get_input(&year, &day, &context).await;
-    match input {
-        Ok(lines) => {
-            let start_time = std::time::Instant::now();
-            let (part1, part2) = match day {
-                1 => (day1::part1(&lines) as i64, day1::part2(&lines) as i64),
-                2 => (day2::part1(&lines) as i64, day2::part2(&lines) as i64),
-                3 => (day3::part1(&lines) as i64, day3::part2(&lines) as i64),
-                4 => (day4::part1(&lines) as i64, day4::part2(&lines) as i64),
-                5 => (day5::part1(&lines) as i64, day5::part2(&lines) as i64),
-                6 => (day6::part1(&lines) as i64, day6::part2(&lines) as i64),
-                7 => (day7::part1(&lines), day7::part2(&lines)),
-                9 => (day9::part1(&lines), day9::part2(&lines)),
-                10 => (day10::part1(&lines), day10::part2(&lines)),
-                11 => (day11::part1(&lines), day11::part2(&lines)),
-                13 => (day13::part1(&lines), day13::part2(&lines)),
-                18 => (day18::part1(&lines), day18::part2(&lines)),
-                19 => (day19::part1(&lines), day19::part2(&lines)),
-                _ => {
-                    println!("Day {} not implemented", day);
-                    process::exit(3);
+
+    let parsed = solution.parse(&input);
+    let answer = match part {
+        1 => solution.part1(&parsed),
+        _ => solution.part2(&parsed),
+    };
+    println!("Part {}: {}", part, answer);
+
+    if let Err(reason) = ledger.check(part, &answer) {
+        println!("Not submitting part {}: {}", part, reason);
+        return 6;
+    }
+
+    match context.answer_url(&year, &day) {
+        Ok(url) => println!("Submitting answer to {}", url),
+        Err(e) => {
+            println!("Error: {}", e);
+            return 5;
+        }
+    }
+
+    match aoc::submit_answer(&year, &day, &part, &answer, context).await {
+        Ok(verdict) => {
+            println!("Part {}: {}", part, verdict);
+            match ledger.record(part, &answer, &verdict) {
+                Ok(()) => 0,
+                Err(e) => {
+                    println!("Error: {}", e);
+                    5
                 }
-            };
-            let duration = start_time.elapsed();
-            println!("Elapsed: {:?}", duration);
-            println!("Part 1: {}", part1);
-            println!("Part 2: {}", part2);
+            }
+        }
+        Err(e) => {
+            println!("Error: {}", e);
+            5
+        }
+    }
+}
+
+/// Prints the answer to a part and checks it against the expected answer when one is known.
+///
+/// # Returns
+///
+/// * `bool` - `false` if the answer differs from the expected answer.
+fn report(part: i32, answer: &Answer, expected: Option<Answer>) -> bool {
+    match expected {
+        Some(expected) if &expected != answer => {
+            println!("Part {}: {} (expected {})", part, answer, expected);
+            false
+        }
+        Some(_) => {
+            println!("Part {}: {} (verified)", part, answer);
+            true
+        }
+        None => {
+            println!("Part {}: {}", part, answer);
+            true
         }
-        Err(e) => println!("Error: {}", e),
     }
 }
@@ -0,0 +1,217 @@
+use crate::batch::{self, Failure, Outcome, Schedule};
+use aoc::{Answer, Context, Source};
+use clap::ValueEnum;
+use serde::Serialize;
+use std::io::Write;
+
+/// How the results of a run are written.
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum Format {
+    /// Human readable progress and answers
+    Text,
+    /// An array of records
+    Json,
+    /// A header followed by one line per record
+    Csv,
+}
+
+/// The result of running one part of one day.
+///
+/// This is the schema consumed by other tools so fields may be added but never renamed or removed.
+#[derive(Serialize, Debug, PartialEq)]
+pub struct Record {
+    pub year: i32,
+    pub day: i32,
+    pub part: i32,
+    /// The answer, absent when the part is unsolved or could not be run.
+    pub answer: Option<String>,
+    /// How long parsing the input took in nanoseconds, shared by both parts of a day.
+    pub parse_ns: Option<u64>,
+    /// How long solving the part took in nanoseconds.
+    pub solve_ns: Option<u64>,
+    /// Either `hit` or `miss` depending on whether the input was already cached.
+    pub cache: Option<&'static str>,
+    pub error: Option<String>,
+}
+
+/// Converts the outcome of a day into a record for each of its parts.
+///
+/// # Arguments
+///
+/// * `outcome` - The outcome of running the day.
+/// * `expected` - The known answers to each part, a differing answer is recorded as an error.
+fn to_records(outcome: &Outcome, expected: &[Option<Answer>; 2]) -> Vec<Record> {
+    let cache = outcome.source.map(|source| match source {
+        Source::Cache => "hit",
+        Source::Site => "miss",
+    });
+
+    (1..=2)
+        .map(|part| {
+            let mut record = Record {
+                year: outcome.year,
+                day: outcome.day,
+                part,
+                answer: None,
+                parse_ns: None,
+                solve_ns: None,
+                cache,
+                error: None,
+            };
+
+            match &outcome.result {
+                Ok(solved) => {
+                    let (answer, duration) = if part == 1 { &solved.part1 } else { &solved.part2 };
+                    record.answer = answer.is_solved().then(|| answer.to_string());
+                    record.parse_ns = Some(solved.parse.as_nanos() as u64);
+                    record.solve_ns = Some(duration.as_nanos() as u64);
+                    record.error = match &expected[part as usize - 1] {
+                        Some(expected) if expected != answer => Some(format!("expected {}", expected)),
+                        _ => None,
+                    };
+                }
+                Err(failure) => record.error = Some(failure.to_string()),
+            }
+
+            record
+        })
+        .collect()
+}
+
+/// Writes the records in the requested format.
+fn write(format: Format, records: &[Record], out: &mut impl Write) -> Result<(), String> {
+    match format {
+        Format::Json => {
+            let body = serde_json::to_string_pretty(records).map_err(|e| e.to_string())?;
+            writeln!(out, "{}", body).map_err(|e| e.to_string())
+        }
+        Format::Csv => {
+            let mut writer = csv::Writer::from_writer(out);
+            for record in records {
+                writer.serialize(record).map_err(|e| e.to_string())?;
+            }
+            writer.flush().map_err(|e| e.to_string())
+        }
+        Format::Text => unreachable!("text output is written while the days run"),
+    }
+}
+
+/// Runs several days of a year and writes a record for each part without reporting progress.
+///
+/// # Arguments
+///
+/// * `year` - The year of the event.
+/// * `days` - The days to run, or `None` to run every registered day of the year.
+/// * `schedule` - How the days are run.
+/// * `overrides` - Answers given on the command line which take the place of those the ledger expects.
+/// * `format` - How the records are written.
+/// * `out` - Where the records are written, normally standard output.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `i32` - The exit code, non-zero if a day could not be run or an answer differs from its known answer.
+pub async fn run(year: i32, days: Option<Vec<i32>>, schedule: Schedule, overrides: [Option<Answer>; 2], format: Format, out: &mut impl Write, context: &Context) -> i32 {
+    let single = days.as_ref().is_some_and(|days| days.len() == 1);
+    let outcomes = batch::execute(year, days, schedule, true, context).await;
+
+    let mut exit_code = 0;
+    let mut records = vec![];
+    for outcome in &outcomes {
+        let expected = [overrides[0].clone().or(outcome.expected[0].clone()), overrides[1].clone().or(outcome.expected[1].clone())];
+        let day_records = to_records(outcome, &expected);
+        let code = match &outcome.result {
+            Err(Failure::NotImplemented) if single => 3,
+            Err(Failure::NotImplemented) => 0,
+            Err(Failure::Input(_) | Failure::TimedOut(_) | Failure::Panicked(_)) => 5,
+            Ok(_) if day_records.iter().any(|r| r.error.is_some()) => 4,
+            Ok(_) => 0,
+        };
+        exit_code = exit_code.max(code);
+        records.extend(day_records);
+    }
+
+    if let Err(e) = write(format, &records, out) {
+        eprintln!("Error: {}", e);
+        return 5;
+    }
+
+    exit_code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::batch::Solved;
+    use aoc::AocError;
+    use std::time::Duration;
+
+    fn outcome(result: Result<Solved, Failure>) -> Outcome {
+        Outcome {
+            year: 2024,
+            day: 3,
+            title: "Mull It Over",
+            source: Some(Source::Cache),
+            expected: [None, None],
+            result,
+        }
+    }
+
+    fn solved() -> Solved {
+        Solved {
+            parse: Duration::from_nanos(10),
+            part1: (Answer::Number(42), Duration::from_nanos(20)),
+            part2: (Answer::Unsolved, Duration::from_nanos(30)),
+        }
+    }
+
+    #[test]
+    fn records_are_produced_for_each_part() {
+        let records = to_records(&outcome(Ok(solved())), &[None, None]);
+        assert_eq!(
+            records,
+            vec![
+                Record {
+                    year: 2024,
+                    day: 3,
+                    part: 1,
+                    answer: Some("42".to_string()),
+                    parse_ns: Some(10),
+                    solve_ns: Some(20),
+                    cache: Some("hit"),
+                    error: None,
+                },
+                Record {
+                    year: 2024,
+                    day: 3,
+                    part: 2,
+                    answer: None,
+                    parse_ns: Some(10),
+                    solve_ns: Some(30),
+                    cache: Some("hit"),
+                    error: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn differing_answers_are_errors() {
+        let records = to_records(&outcome(Ok(solved())), &[Some(Answer::Number(41)), None]);
+        assert_eq!(records[0].error, Some("expected 41".to_string()));
+        assert_eq!(records[1].error, None);
+    }
+
+    #[test]
+    fn failures_are_recorded_against_both_parts() {
+        let records = to_records(&outcome(Err(Failure::Input(AocError::Network("offline".to_string())))), &[None, None]);
+        assert!(records.iter().all(|r| r.answer.is_none() && r.error == Some("offline".to_string())));
+    }
+
+    #[test]
+    fn schema_is_stable() {
+        let records = to_records(&outcome(Ok(solved())), &[None, None]);
+        let json = serde_json::to_string(&records[0]).unwrap();
+        assert_eq!(json, r#"{"year":2024,"day":3,"part":1,"answer":"42","parse_ns":10,"solve_ns":20,"cache":"hit","error":null}"#);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::batch;
+use aoc::samples::Example;
+use aoc::{Answer, AocError, Context};
+use std::fs;
+use std::path::Path;
+
+const MANIFEST: &str = include_str!("../templates/Cargo.toml.tpl");
+const SOLUTION: &str = include_str!("../templates/lib.rs.tpl");
+
+/// What is known about a puzzle when its crate is generated.
+#[derive(Default)]
+struct Puzzle {
+    title: String,
+    examples: Vec<Example>,
+}
+
+/// Generates the crate for a new day and adds it to the runner.
+///
+/// The crate is created in `crates/year{year}/day{day}` of the workspace in the current directory with a
+/// solution answering neither part, so its sample tests fail until it is written. When `fetch` is
+/// set the input is cached and the description supplies the title, the sample and its answers.
+///
+/// # Returns
+///
+/// * `i32` - The exit code.
+pub async fn new_day(year: i32, day: i32, fetch: bool, context: &Context) -> i32 {
+    let crates = Path::new("crates");
+    let runner = crates.join("runner").join("Cargo.toml");
+    let dir = crates.join(format!("year{}", year)).join(format!("day{}", day));
+    if !runner.exists() {
+        println!("{} not found, run this from the root of the workspace", runner.display());
+        return 1;
+    }
+    if dir.exists() {
+        println!("{} already exists", dir.display());
+        return 1;
+    }
+
+    let puzzle = if fetch {
+        match fetch_puzzle(year, day, context).await {
+            Ok(puzzle) => puzzle,
+            Err(e) => {
+                println!("Error: {}", e);
+                return 5;
+            }
+        }
+    } else {
+        Puzzle::default()
+    };
+
+    if let Err(e) = generate(year, day, &puzzle, &dir, &runner) {
+        println!("Error: {}", e);
+        return 5;
+    }
+
+    match puzzle.title.as_str() {
+        "" => println!("Created {} for {} Day {}", dir.display(), year, day),
+        title => println!("Created {} for {} Day {}: {}", dir.display(), year, day, title),
+    }
+    if fetch {
+        println!("{} examples extracted, check src/sample.dat before relying on it", puzzle.examples.len());
+    } else {
+        println!("Fill in src/sample.dat and the expected answers of the sample tests");
+    }
+    0
+}
+
+async fn fetch_puzzle(year: i32, day: i32, context: &Context) -> Result<Puzzle, AocError> {
+    batch::get_input(year, day, context).await?;
+    aoc::puzzle::get_description(&year, &day, false, context).await?;
+    let page = aoc::puzzle::get_page_from_cache(&year, &day, context)?.unwrap_or_default();
+
+    Ok(Puzzle {
+        title: aoc::puzzle::title(&page).unwrap_or_default(),
+        examples: aoc::samples::extract(&page),
+    })
+}
+
+fn generate(year: i32, day: i32, puzzle: &Puzzle, dir: &Path, runner: &Path) -> Result<(), AocError> {
+    // the sample tests use the example the description answers, usually the main one
+    let sample = puzzle.examples.iter().find(|e| e.answer(1).is_some()).or(puzzle.examples.first());
+    let expected = |part: i32| match sample.and_then(|e| e.answer(part)) {
+        Some(Answer::Number(x)) => x.to_string(),
+        _ => "0".to_string(),
+    };
+
+    let fill = |template: &str| {
+        template
+            .replace("{{year}}", &year.to_string())
+            .replace("{{day}}", &day.to_string())
+            .replace("{{title}}", &puzzle.title.replace('\\', "\\\\").replace('"', "\\\""))
+            .replace("{{part1}}", &expected(1))
+            .replace("{{part2}}", &expected(2))
+    };
+
+    let src = dir.join("src");
+    fs::create_dir_all(&src)?;
+    fs::write(dir.join("Cargo.toml"), fill(MANIFEST))?;
+    fs::write(src.join("lib.rs"), fill(SOLUTION))?;
+    fs::write(src.join("sample.dat"), sample.map_or("", |e| e.input.raw()))?;
+    if !puzzle.examples.is_empty() {
+        aoc::samples::save(&src.join("samples"), &puzzle.examples)?;
+    }
+
+    let manifest = fs::read_to_string(runner)?;
+    fs::write(runner, add_dependency(&manifest, year, day))?;
+
+    Ok(())
+}
+
+/// Adds the crate of a day to the dependencies of the runner, keeping the days in order.
+fn add_dependency(manifest: &str, year: i32, day: i32) -> String {
+    let mut lines: Vec<String> = manifest.lines().map(|line| line.to_string()).collect();
+    let days: Vec<(usize, (i32, i32))> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let (y, d) = line.strip_prefix("year")?.split_once(' ')?.0.split_once("-day")?;
+            Some((i, (y.parse().ok()?, d.parse().ok()?)))
+        })
+        .collect();
+
+    let position = match days.iter().find(|(_, puzzle)| *puzzle > (year, day)) {
+        Some((i, _)) => *i,
+        None => match days.last() {
+            Some((i, _)) => i + 1,
+            None => lines.iter().position(|line| line.trim() == "[dependencies]").map_or(lines.len(), |i| i + 1),
+        },
+    };
+    lines.insert(position, format!("year{0}-day{1} = {{ path = \"../year{0}/day{1}\" }}", year, day));
+
+    format!("{}\n", lines.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RUNNER: &str = "[package]\nname = \"runner\"\n\n[dependencies]\naoc = { path = \"../aoc\" }\nyear2023-day1 = { path = \"../year2023/day1\" }\nyear2024-day1 = { path = \"../year2024/day1\" }\nyear2024-day10 = { path = \"../year2024/day10\" }\nserde = \"1.0\"\n";
+
+    #[test]
+    fn days_are_added_in_order() {
+        assert!(add_dependency(RUNNER, 2024, 5).contains("year2024-day1 = { path = \"../year2024/day1\" }\nyear2024-day5 = { path = \"../year2024/day5\" }\nyear2024-day10"));
+        assert!(add_dependency(RUNNER, 2024, 12).contains("year2024-day10 = { path = \"../year2024/day10\" }\nyear2024-day12 = { path = \"../year2024/day12\" }\nserde"));
+        assert!(add_dependency(RUNNER, 2023, 25).contains("year2023-day1 = { path = \"../year2023/day1\" }\nyear2023-day25 = { path = \"../year2023/day25\" }\nyear2024-day1"));
+    }
+
+    #[test]
+    fn crate_is_generated_from_the_examples() {
+        let root = std::env::temp_dir().join("runner_scaffold_test");
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(&root).unwrap();
+        let runner = root.join("Cargo.toml");
+        fs::write(&runner, RUNNER).unwrap();
+
+        let example = |name: &str, input: &str, answers| Example {
+            name: name.to_string(),
+            input: aoc::Input::from(input),
+            answers,
+        };
+        let puzzle = Puzzle {
+            title: "Print \"Queue\"".to_string(),
+            examples: vec![example("example1", "47|53\n", [None, None]), example("example2", "47|53\n97|13\n", [Some(Answer::from(143)), None])],
+        };
+        let dir = root.join("year2024").join("day5");
+        generate(2024, 5, &puzzle, &dir, &runner).unwrap();
+
+        let solution = fs::read_to_string(dir.join("src").join("lib.rs")).unwrap();
+        assert!(solution.contains("\"Print \\\"Queue\\\"\""));
+        assert!(solution.contains("aoc::sample_test!(Day5, \"sample.dat\", part1 = Some(143), part2 = Some(0));"));
+        assert_eq!(fs::read_to_string(dir.join("src").join("sample.dat")).unwrap(), "47|53\n97|13\n");
+        assert_eq!(aoc::samples::load(&dir.join("src").join("samples")).unwrap(), puzzle.examples);
+        assert!(fs::read_to_string(dir.join("Cargo.toml")).unwrap().contains("name = \"year2024-day5\""));
+        assert!(fs::read_to_string(&runner).unwrap().contains("year2024-day5 = { path = \"../year2024/day5\" }"));
+    }
+
+    #[test]
+    fn first_day_follows_the_dependencies_header() {
+        let manifest = add_dependency("[package]\nname = \"runner\"\n\n[dependencies]\nserde = \"1.0\"\n", 2024, 1);
+        assert_eq!(
+            manifest,
+            "[package]\nname = \"runner\"\n\n[dependencies]\nyear2024-day1 = { path = \"../year2024/day1\" }\nserde = \"1.0\"\n"
+        );
+    }
+}
@@ -0,0 +1,208 @@
+use crate::batch;
+use crate::table::Table;
+use aoc::ledger::Ledger;
+use aoc::{Answer, Context, Input};
+use std::time::Duration;
+
+/// The outcome of checking one part against its expected answer.
+#[derive(PartialEq)]
+enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+fn check(answer: &Answer, expected: Option<&Answer>) -> Status {
+    match expected {
+        Some(expected) if expected == answer => Status::Pass,
+        Some(_) => Status::Fail,
+        None => Status::Unknown,
+    }
+}
+
+fn describe(answer: &Answer, expected: Option<&Answer>) -> String {
+    match (check(answer, expected), expected) {
+        (Status::Fail, Some(expected)) => format!("{} (expected {})", answer, expected),
+        _ => answer.to_string(),
+    }
+}
+
+/// Runs every registered solution against its cached input and compares the answers with those
+/// accepted by the site or recorded as expected.
+///
+/// When recording, the answers to parts without an expected answer are stored in the ledger as
+/// expected, so answers found outside of `runner submit` can be verified from then on. They are
+/// kept apart from the verdicts of the site and never stop an answer from being submitted.
+/// Answers which differ from an expected one are never recorded.
+///
+/// # Arguments
+///
+/// * `year` - Only verify the solutions for the specified year.
+/// * `day` - Only verify the solutions for the specified day.
+/// * `record` - Record the answers to parts without an expected answer as expected.
+/// * `limit` - The longest a day may take, or `None` to wait for as long as it takes.
+/// * `context` - Defines the context to interact with the Advent of Code website.
+///
+/// # Returns
+///
+/// * `i32` - The exit code, non-zero if any answer differs from its expected answer or a day timed out or panicked.
+pub fn run(year: Option<i32>, day: Option<i32>, record: bool, limit: Option<Duration>, context: &Context) -> i32 {
+    let mut table = Table::new(&["Year", "Day", "Title", "Part 1", "Part 2", "Elapsed", "Result"]);
+    let mut failed = false;
+    let mut unfinished = false;
+
+    let solutions = aoc::registry::solutions()
+        .into_iter()
+        .filter(|s| year.is_none_or(|y| y == s.year()) && day.is_none_or(|d| d == s.day()));
+
+    for solution in solutions {
+        let (y, d) = (solution.year(), solution.day());
+        let mut row = vec![y.to_string(), d.to_string(), solution.title().to_string()];
+
+        let input = match aoc::get_input_from_cache(&y, &d, context) {
+            Ok(Some(body)) => Input::new(body),
+            Err(e) => {
+                println!("Error: {}", e);
+                return 5;
+            }
+            Ok(None) => {
+                row.extend(["".to_string(), "".to_string(), "".to_string(), "NO INPUT".to_string()]);
+                table.add_row(row);
+                continue;
+            }
+        };
+
+        let mut ledger = match Ledger::load(&y, &d, context) {
+            Ok(ledger) => ledger,
+            Err(e) => {
+                println!("Error: {}", e);
+                return 5;
+            }
+        };
+
+        let solved = match batch::solve_within(solution, &input, limit) {
+            Ok(solved) => solved,
+            Err(failure) => {
+                unfinished = true;
+                let result = if matches!(failure, batch::Failure::TimedOut(_)) { "TIMED OUT" } else { "PANICKED" };
+                row.extend(["".to_string(), "".to_string(), failure.to_string(), result.to_string()]);
+                table.add_row(row);
+                continue;
+            }
+        };
+        let (part1, part2, duration) = (solved.part1.0.clone(), solved.part2.0.clone(), solved.total());
+
+        let mut recorded = false;
+        for (part, answer) in [(1, &part1), (2, &part2)] {
+            if record && answer.is_solved() && ledger.expected(part).is_none() {
+                if let Err(e) = ledger.expect(part, answer) {
+                    println!("Error: {}", e);
+                    return 5;
+                }
+                recorded = true;
+            }
+        }
+
+        let statuses = [check(&part1, ledger.expected(1)), check(&part2, ledger.expected(2))];
+        let result = if statuses.contains(&Status::Fail) {
+            failed = true;
+            "FAIL"
+        } else if recorded {
+            "RECORDED"
+        } else if statuses.contains(&Status::Unknown) {
+            "UNVERIFIED"
+        } else {
+            "PASS"
+        };
+
+        row.extend([
+            describe(&part1, ledger.expected(1)),
+            describe(&part2, ledger.expected(2)),
+            format!("{:?}", duration),
+            result.to_string(),
+        ]);
+        table.add_row(row);
+    }
+
+    table.print();
+
+    if failed {
+        4
+    } else if unfinished {
+        5
+    } else {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc::http::HttpClient;
+    use aoc::unlock::SystemClock;
+    use aoc::Solution;
+    use std::sync::Arc;
+    use url::Url;
+
+    struct Counter;
+
+    impl Solution for Counter {
+        type Input = Vec<i128>;
+        type Part1 = i128;
+        type Part2 = Answer;
+
+        fn year(&self) -> i32 {
+            2015
+        }
+
+        fn day(&self) -> i32 {
+            24
+        }
+
+        fn title(&self) -> &'static str {
+            "Counter"
+        }
+
+        fn parse(&self, input: &Input) -> Vec<i128> {
+            input.ints()
+        }
+
+        fn part1(&self, input: &Vec<i128>) -> i128 {
+            input.iter().sum()
+        }
+
+        fn part2(&self, _: &Vec<i128>) -> Answer {
+            Answer::Unsolved
+        }
+    }
+
+    aoc::register!(Counter);
+
+    #[test]
+    fn answers_are_recorded_as_expected() {
+        let data_dir = std::env::temp_dir().join("runner_verify_test");
+        let _ = std::fs::remove_dir_all(&data_dir);
+        let context = Context {
+            session_id: String::new(),
+            data_dir,
+            url: Url::parse("https://adventofcode.com").unwrap(),
+            profile: None,
+            clock: Arc::new(SystemClock),
+            http: HttpClient::default(),
+        };
+        aoc::add_to_cache(&2015, &24, "1 2 3\n", 200, &context).unwrap();
+
+        assert_eq!(run(Some(2015), Some(24), true, None, &context), 0);
+        let ledger = Ledger::load(&2015, &24, &context).unwrap();
+        assert_eq!(ledger.expected(1), Some(&Answer::from(6)));
+        assert_eq!(ledger.expected(2), None);
+        // the answer was never submitted, so it is no reason to refuse a submission
+        assert_eq!(ledger.correct(1), None);
+        assert!(ledger.check(1, &Answer::from(6)).is_ok());
+
+        // a different answer is reported rather than replacing the expected one
+        aoc::add_to_cache(&2015, &24, "1 2 4\n", 200, &context).unwrap();
+        assert_eq!(run(Some(2015), Some(24), true, None, &context), 4);
+        assert_eq!(Ledger::load(&2015, &24, &context).unwrap().expected(1), Some(&Answer::from(6)));
+    }
+}
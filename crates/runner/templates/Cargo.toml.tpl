@@ -0,0 +1,7 @@
+[package]
+name = "year{{year}}-day{{day}}"
+version = "0.1.0"
+edition = "2021"
+
+[dependencies]
+aoc = { path = "../../aoc" }
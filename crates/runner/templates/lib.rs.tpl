@@ -0,0 +1,52 @@
+pub fn parse(input: &aoc::Input) -> Vec<String> {
+    input.lines().iter().map(|line| line.to_string()).collect()
+}
+
+pub fn part1(_input: &[String]) -> Option<i64> {
+    None
+}
+
+pub fn part2(_input: &[String]) -> Option<i64> {
+    None
+}
+
+pub struct Day{{day}};
+
+impl aoc::Solution for Day{{day}} {
+    type Input = Vec<String>;
+    type Part1 = Option<i64>;
+    type Part2 = Option<i64>;
+
+    fn year(&self) -> i32 {
+        {{year}}
+    }
+
+    fn day(&self) -> i32 {
+        {{day}}
+    }
+
+    fn title(&self) -> &'static str {
+        "{{title}}"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> Option<i64> {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> Option<i64> {
+        part2(input)
+    }
+}
+
+aoc::register!(Day{{day}});
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day{{day}}, "sample.dat", part1 = Some({{part1}}), part2 = Some({{part2}}));
+}
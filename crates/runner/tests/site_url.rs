@@ -0,0 +1,18 @@
+use std::process::Command;
+
+#[test]
+fn invalid_site_addresses_are_rejected() {
+    let runner = || {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_runner"));
+        command.args(["verify", "--year", "2015"]).current_dir(std::env::temp_dir()).env_remove("AOC_URL");
+        command
+    };
+
+    let output = runner().args(["--url", "ftp://example.com"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("not an http or https address"));
+
+    let output = runner().env("AOC_URL", "nonsense").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("nonsense is not a valid address"));
+}
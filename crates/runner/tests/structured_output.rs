@@ -0,0 +1,34 @@
+use aoc::http::HttpClient;
+use aoc::unlock::SystemClock;
+use aoc::Context;
+use std::fs;
+use std::process::Command;
+use std::sync::Arc;
+use url::Url;
+
+#[test]
+fn records_are_not_mixed_with_progress() {
+    let root = std::env::temp_dir().join("runner_structured_output_test");
+    let _ = fs::remove_dir_all(&root);
+    let context = Context {
+        session_id: String::new(),
+        data_dir: root.join("Data"),
+        url: Url::parse("https://adventofcode.com").unwrap(),
+        profile: None,
+        clock: Arc::new(SystemClock),
+        http: HttpClient::default(),
+    };
+    aoc::add_to_cache(&2024, &7, include_str!("../../year2024/day7/src/sample.dat"), 200, &context).unwrap();
+
+    let output = Command::new(env!("CARGO_BIN_EXE_runner"))
+        .args(["run", "--year", "2024", "--day", "7", "--format", "json"])
+        .current_dir(&root)
+        .env_remove("AOC_SESSION")
+        .output()
+        .unwrap();
+
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+    let records: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(records[0]["answer"], "3749");
+    assert_eq!(records[1]["answer"], "11387");
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+fn calculate_distances(x: &Vec<i32>, y: &Vec<i32>) -> Vec<i32> {
+    x.iter().zip(y.iter()).map(|(a, b)| (a - b).abs()).collect()
+}
+
+fn parse_input(input: &aoc::Input) -> (Vec<i32>, Vec<i32>) {
+    input.ints().chunks_exact(2).map(|pair| (pair[0] as i32, pair[1] as i32)).unzip()
+}
+
+fn get_frequency(number: i32, input: &Vec<i32>) -> i32 {
+    input.iter().filter(|x| x == &&number).count() as i32
+}
+
+pub fn part1(input: &(Vec<i32>, Vec<i32>)) -> i32 {
+    let (mut left, mut right) = input.clone();
+    left.sort();
+    right.sort();
+
+    calculate_distances(&left, &right).iter().sum()
+}
+pub fn part2(input: &(Vec<i32>, Vec<i32>)) -> i32 {
+    let (left, right) = input;
+
+    let mut frequencies = HashMap::new();
+
+    left.iter().for_each(|x| {
+        if !(frequencies.contains_key(x)) {
+            frequencies.insert(x, get_frequency(*x, right));
+        }
+    });
+
+    left.iter().map(|x| x * frequencies.get(x).unwrap()).sum()
+}
+
+pub struct Day1;
+
+impl aoc::Solution for Day1 {
+    type Input = (Vec<i32>, Vec<i32>);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        1
+    }
+
+    fn title(&self) -> &'static str {
+        "Historian Hysteria"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse_input(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day1);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day1, "sample.dat", part1 = 11, part2 = 31);
+}
@@ -27,11 +27,8 @@ fn pretty_print_path(trail: &Trail) {
     aoc::pretty_print(&matrix);
 }
 
-fn to_matrix(input: &Vec<String>) -> Vec<Vec<i32>> {
-    input
-        .iter()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap() as i32).collect())
-        .collect()
+fn to_matrix(input: &aoc::Input) -> Vec<Vec<i32>> {
+    input.grid().iter().map(|row| row.iter().map(|c| c.to_digit(10).unwrap() as i32).collect()).collect()
 }
 
 fn walk_trail(map: &Vec<Vec<i32>>, path: Vec<(usize, usize)>, x: usize, y: usize) -> Vec<Trail> {
@@ -66,16 +63,7 @@ fn walk_trail(map: &Vec<Vec<i32>>, path: Vec<(usize, usize)>, x: usize, y: usize
     }
 }
 
-fn explore_direction(
-    map: &Vec<Vec<i32>>,
-    path: &Vec<(usize, usize)>,
-    x: usize,
-    y: usize,
-    target: i32,
-    result: &mut HashSet<Trail>,
-    dx: isize,
-    dy: isize,
-) {
+fn explore_direction(map: &Vec<Vec<i32>>, path: &Vec<(usize, usize)>, x: usize, y: usize, target: i32, result: &mut HashSet<Trail>, dx: isize, dy: isize) {
     let new_x = (x as isize + dx) as usize;
     let new_y = (y as isize + dy) as usize;
     if new_x < map[0].len() && new_y < map.len() && map[new_y][new_x] == target {
@@ -104,60 +92,70 @@ fn mark_singular_trails(input: &Vec<Vec<i32>>, x: usize, y: usize) -> Vec<Trail>
 }
 
 fn mark_distinct_trails(input: &Vec<Vec<i32>>, x: usize, y: usize) -> Vec<Trail> {
-    walk_trail(input, vec![(x, y)], x, y)
-        .into_iter()
-        .filter(|trail| trail.path.len() == 10)
-        .collect::<Vec<_>>()
+    walk_trail(input, vec![(x, y)], x, y).into_iter().filter(|trail| trail.path.len() == 10).collect::<Vec<_>>()
 }
 
-pub fn part1(input: &Vec<String>) -> i64 {
-    calculate_trailheads(input, mark_singular_trails)
+pub fn part1(topographical_map: &Vec<Vec<i32>>) -> i64 {
+    calculate_trailheads(topographical_map, mark_singular_trails)
 }
 
-pub fn part2(input: &Vec<String>) -> i64 {
-    calculate_trailheads(input, mark_distinct_trails)
+pub fn part2(topographical_map: &Vec<Vec<i32>>) -> i64 {
+    calculate_trailheads(topographical_map, mark_distinct_trails)
 }
 
-fn calculate_trailheads<F>(input: &Vec<String>, trail_fn: F) -> i64
+fn calculate_trailheads<F>(topographical_map: &Vec<Vec<i32>>, trail_fn: F) -> i64
 where
     F: Fn(&Vec<Vec<i32>>, usize, usize) -> Vec<Trail>,
 {
-    let topographical_map = to_matrix(input);
     let mut trailheads = Vec::new();
     for (y, line) in topographical_map.iter().enumerate() {
         for (x, c) in line.iter().enumerate() {
             if c == &0 {
-                let paths = trail_fn(&topographical_map, x, y);
-                trailheads.push(Trailhead {
-                    x,
-                    y,
-                    count: paths.len() as i32,
-                });
+                let paths = trail_fn(topographical_map, x, y);
+                trailheads.push(Trailhead { x, y, count: paths.len() as i32 });
             }
         }
     }
     trailheads.iter().map(|x| x.count).sum::<i32>() as i64
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day10;
+
+impl aoc::Solution for Day10 {
+    type Input = Vec<Vec<i32>>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        10
+    }
 
-    fn get_sample() -> Vec<String> {
-        include_str!("sample.dat").lines().map(|line| line.to_string()).collect()
+    fn title(&self) -> &'static str {
+        "Hoof It"
     }
 
-    #[test]
-    fn sample_part1_valid() {
-        let sample = get_sample();
-        let result = part1(&sample);
-        assert_eq!(result, 36);
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        to_matrix(input)
     }
 
-    #[test]
-    fn sample_part2_valid() {
-        let sample = get_sample();
-        let result = part2(&sample);
-        assert_eq!(result, 81);
+    fn part1(&self, input: &Self::Input) -> i64 {
+        part1(input)
     }
+
+    fn part2(&self, input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day10);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day10, "sample.dat", part1 = 36, part2 = 81);
 }
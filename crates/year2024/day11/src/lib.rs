@@ -1,13 +1,13 @@
 use std::collections::HashMap;
 
-#[derive(Eq, PartialEq, Debug)]
-struct Stone {
+#[derive(Eq, PartialEq, Debug, Clone)]
+pub struct Stone {
     value: i64,
     count: i64,
 }
 
-fn parse_stones(input: &Vec<String>) -> Vec<Stone> {
-    input.iter().map(|x| x.split_whitespace()).flatten().map(|x| Stone { value: x.parse::<i64>().unwrap(), count: 1 }).collect()
+fn parse_stones(input: &aoc::Input) -> Vec<Stone> {
+    input.ints().into_iter().map(|value| Stone { value: value as i64, count: 1 }).collect()
 }
 
 pub fn blink(stones: &Vec<i64>) -> Vec<i64> {
@@ -33,8 +33,8 @@ pub fn blink(stones: &Vec<i64>) -> Vec<i64> {
     result
 }
 
-pub fn part1(input: &Vec<String>) -> i64 {
-    let mut stones = parse_stones(&input);
+pub fn part1(input: &Vec<Stone>) -> i64 {
+    let mut stones = input.clone();
     for _ in 0..25 {
         stones = blink_and_condense(&stones);
     }
@@ -42,8 +42,8 @@ pub fn part1(input: &Vec<String>) -> i64 {
     stones.iter().map(|s| s.count).sum()
 }
 
-pub fn part2(input: &Vec<String>) -> i64 {
-    let mut stones = parse_stones(&input);
+pub fn part2(input: &Vec<Stone>) -> i64 {
+    let mut stones = input.clone();
     for _ in 0..75 {
         stones = blink_and_condense(&stones);
     }
@@ -63,7 +63,7 @@ fn blink_and_condense(stones: &Vec<Stone>) -> Vec<Stone> {
                 let right = text[text.len() / 2..text.len()].parse::<i64>().unwrap();
                 *cache.entry(left).or_insert(0) += stone.count;
                 *cache.entry(right).or_insert(0) += stone.count;
-            },
+            }
             x => {
                 let key = x * 2024;
                 *cache.entry(key).or_insert(0) += stone.count;
@@ -73,17 +73,44 @@ fn blink_and_condense(stones: &Vec<Stone>) -> Vec<Stone> {
     cache.into_iter().map(|(value, count)| Stone { value, count }).collect()
 }
 
+pub struct Day11;
+
+impl aoc::Solution for Day11 {
+    type Input = Vec<Stone>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        11
+    }
+
+    fn title(&self) -> &'static str {
+        "Plutonian Pebbles"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse_stones(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day11);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn get_sample() -> Vec<String> {
-        include_str!("sample.dat")
-            .lines()
-            .map(|line| line.to_string())
-            .collect()
-    }
-
     #[test]
     fn single_blink() {
         let stones = vec![0, 1, 10, 99, 999];
@@ -91,17 +118,5 @@ mod tests {
         assert_eq!(result, vec![1, 2024, 1, 0, 9, 9, 2021976]);
     }
 
-    #[test]
-    fn sample_part1_full() {
-        let sample = get_sample();
-        let result = part1(&sample);
-        assert_eq!(result, 55312);
-    }
-
-    #[test]
-    fn sample_part2_valid() {
-        let sample = get_sample();
-        let result = part2(&sample);
-        assert_eq!(result, 65601038650482);
-    }
+    aoc::sample_test!(Day11, "sample.dat", part1 = 55312, part2 = 65601038650482);
 }
@@ -0,0 +1,134 @@
+/// The distance the prizes actually are once the unit conversion error is corrected.
+const CONVERSION_OFFSET: usize = 10000000000000;
+
+#[derive(Debug)]
+pub struct Machine {
+    a: (usize, usize),
+    b: (usize, usize),
+    prize: (usize, usize),
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+struct Play {
+    a: i64,
+    b: i64,
+}
+
+impl Play {
+    fn calculate_tokens(&self) -> i64 {
+        self.a * 3 + self.b
+    }
+}
+
+fn parse(input: &aoc::Input) -> Vec<Machine> {
+    input
+        .sections()
+        .iter()
+        .map(|section| match section.ints().as_slice() {
+            [ax, ay, bx, by, px, py] => Machine {
+                a: (*ax as usize, *ay as usize),
+                b: (*bx as usize, *by as usize),
+                prize: (*px as usize, *py as usize),
+            },
+            x => panic!("Unexpected machine {:?}", x),
+        })
+        .collect()
+}
+
+fn has_solution(matrix: [[u64; 2]; 2], vector: [u64; 2]) -> Option<Play> {
+    // NM = Coefficient Matrix^-1 * Prize Vector
+    let xa = matrix[0][0] as i64;
+    let xb = matrix[0][1] as i64;
+    let ya = matrix[1][0] as i64;
+    let yb = matrix[1][1] as i64;
+    let prize_x = vector[0] as i64;
+    let prize_y = vector[1] as i64;
+
+    // Calculate the determinant
+    let determinant = xa * yb - xb * ya;
+
+    if determinant == 0 {
+        // Determinant is zero: no solutions
+        return None;
+    }
+
+    // Compute the inverse matrix components (scaled by determinant)
+    let inv_xa = yb;
+    let inv_xb = -xb;
+    let inv_ya = -ya;
+    let inv_yb = xa;
+
+    // Multiply the inverse matrix by the prize vector
+    let n = (inv_xa * prize_x + inv_xb * prize_y) / determinant;
+    let m = (inv_ya * prize_x + inv_yb * prize_y) / determinant;
+
+    // Check if n and m are valid (non-negative and integer)
+    if n < 0 || m < 0 || (inv_xa * prize_x + inv_xb * prize_y) % determinant != 0 || (inv_ya * prize_x + inv_yb * prize_y) % determinant != 0 {
+        return None;
+    }
+
+    Some(Play { a: n, b: m })
+}
+
+fn calculate_win_costs(m: &Machine) -> i64 {
+    match has_solution([[m.a.0 as u64, m.b.0 as u64], [m.a.1 as u64, m.b.1 as u64]], [m.prize.0 as u64, m.prize.1 as u64]) {
+        Some(x) => x.calculate_tokens(),
+        _ => 0,
+    }
+}
+
+pub fn part1(machines: &Vec<Machine>) -> i64 {
+    machines.iter().map(|m| calculate_win_costs(m)).sum()
+}
+
+pub fn part2(machines: &Vec<Machine>) -> i64 {
+    machines
+        .iter()
+        .map(|m| Machine {
+            prize: (m.prize.0 + CONVERSION_OFFSET, m.prize.1 + CONVERSION_OFFSET),
+            ..*m
+        })
+        .map(|m| calculate_win_costs(&m))
+        .sum()
+}
+
+pub struct Day13;
+
+impl aoc::Solution for Day13 {
+    type Input = Vec<Machine>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        13
+    }
+
+    fn title(&self) -> &'static str {
+        "Claw Contraption"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day13);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day13, "sample.dat", part1 = 480, part2 = 875318608908);
+}
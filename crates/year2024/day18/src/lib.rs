@@ -0,0 +1,95 @@
+#[derive(Clone)]
+enum Address {
+    Clear,
+    Corrupted,
+    Walked,
+}
+
+pub struct Coordinate {
+    x: usize,
+    y: usize,
+}
+
+fn parse(input: &aoc::Input) -> Vec<Coordinate> {
+    input
+        .ints()
+        .chunks_exact(2)
+        .map(|pair| Coordinate {
+            x: pair[0] as usize,
+            y: pair[1] as usize,
+        })
+        .collect()
+}
+
+fn create_map(x: usize, y: usize, drops: &Vec<Coordinate>, preview: usize) -> Vec<Vec<Address>> {
+    let mut space = vec![vec![Address::Clear; x]; y];
+
+    drops.iter().take(preview).for_each(|d| space[d.y][d.x] = Address::Corrupted);
+
+    space
+}
+
+fn find_exit(drops: &Vec<Coordinate>, bound: (usize, usize), preview: usize) -> i64 {
+    let map = create_map(bound.0, bound.1, drops, preview);
+    navigate(&map, bound)
+}
+
+fn navigate(space: &Vec<Vec<Address>>, exit: (usize, usize)) -> i64 {
+    let mut steps = 0;
+
+    steps
+}
+
+pub struct Day18;
+
+impl aoc::Solution for Day18 {
+    type Input = Vec<Coordinate>;
+    type Part1 = aoc::Answer;
+    type Part2 = aoc::Answer;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        18
+    }
+
+    fn title(&self) -> &'static str {
+        "RAM Run"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(&self, _input: &Self::Input) -> aoc::Answer {
+        aoc::Answer::Unsolved
+    }
+
+    fn part2(&self, _input: &Self::Input) -> aoc::Answer {
+        aoc::Answer::Unsolved
+    }
+}
+
+aoc::register!(Day18);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "navigation is not implemented"]
+    fn sample_valid() {
+        let sample = parse(&aoc::sample!("sample.dat"));
+        let result = find_exit(&sample, (12, 12), 12);
+        assert_eq!(result, 22);
+    }
+
+    #[test]
+    #[ignore = "part 2 is not implemented"]
+    fn sample_part2_valid() {
+        let parsed = aoc::Solution::parse(&Day18, &aoc::sample!("sample.dat"));
+        assert_eq!(aoc::Solution::part2(&Day18, &parsed), aoc::Answer::from("6,1"));
+    }
+}
@@ -0,0 +1,138 @@
+use std::collections::HashSet;
+
+fn parse(input: &aoc::Input) -> (HashSet<String>, Vec<String>) {
+    let inventory = input.first_line().split(", ").map(|s| s.to_string()).collect();
+    let sections = input.sections();
+    let designs = sections.get(1).expect("Missing the designs, expected them after a blank line following the towel patterns");
+    let patterns = designs.lines().iter().map(|l| l.to_string()).collect();
+    (inventory, patterns)
+}
+
+#[derive(Clone, Debug)]
+struct Pattern {
+    index: usize,
+    parts: Vec<String>,
+}
+
+// impl Pattern {
+//     fn get_pattern(&self) -> String {
+//         self.parts.join("")
+//     }
+// }
+
+fn is_part(inventory: &HashSet<String>, width: usize, pattern: &String) -> Option<String> {
+    // println!("\tpattern: {pattern} width: {width}");
+
+    if pattern.len() >= width && inventory.contains(&pattern[0..width]) {
+        Some(pattern[0..width].to_string())
+    } else {
+        None
+    }
+
+    // match pattern.len() >= width {
+    //     true => {
+    //         let key = pattern[0..width].to_string();
+    //         // println!("\t\tKey: {key}");
+    //         match inventory.contains(&key) {
+    //             true => Some(key),
+    //             _ => None
+    //         }
+    //     }
+    //     false => None
+    // }
+}
+
+fn can_build(inventory: &HashSet<String>, patterns: &Vec<String>) -> Vec<String> {
+    patterns
+        .iter()
+        .filter(|p| {
+            let mut options: Vec<Pattern> = vec![];
+            let key_size = inventory.iter().map(|k| k.len()).max().unwrap() + 1;
+
+            // first iteration
+            for i in 1..key_size {
+                match is_part(inventory, i, p) {
+                    Some(x) => options.push(Pattern { index: i, parts: vec![x] }),
+                    _ => (),
+                }
+            }
+
+            // pattern walking
+            while !options.is_empty() && !options.iter().any(|_o| _o.index == p.len()) {
+                let mut opt2 = vec![];
+
+                for opt in options {
+                    for i in 1..key_size {
+                        match is_part(inventory, i, &p[opt.index..].to_string()) {
+                            Some(x) => {
+                                let mut o = opt.clone();
+                                o.index = o.index + i;
+                                o.parts.push(x);
+                                opt2.push(o);
+                            }
+                            _ => (),
+                        }
+                    }
+                }
+
+                options = opt2;
+            }
+
+            !options.is_empty()
+        })
+        .cloned()
+        .collect()
+}
+
+pub fn part1(input: &(HashSet<String>, Vec<String>)) -> i64 {
+    let (inventory, patterns) = input;
+    can_build(inventory, patterns).len() as i64
+}
+
+pub struct Day19;
+
+impl aoc::Solution for Day19 {
+    type Input = (HashSet<String>, Vec<String>);
+    type Part1 = i64;
+    type Part2 = aoc::Answer;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        19
+    }
+
+    fn title(&self) -> &'static str {
+        "Linen Layout"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(&self, _input: &Self::Input) -> aoc::Answer {
+        aoc::Answer::Unsolved
+    }
+}
+
+aoc::register!(Day19);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day19, "sample.dat", part1 = 6);
+
+    #[test]
+    #[ignore = "part 2 is not implemented"]
+    fn sample_part2_valid() {
+        let parsed = aoc::Solution::parse(&Day19, &aoc::sample!("sample.dat"));
+        assert_eq!(aoc::Solution::part2(&Day19, &parsed), 16);
+    }
+}
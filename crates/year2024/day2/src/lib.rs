@@ -5,11 +5,8 @@ enum Change {
     Decreasing,
 }
 
-fn parse_report(report: &String) -> Vec<i32> {
-    report
-        .split_whitespace()
-        .map(|x| x.parse::<i32>().unwrap())
-        .collect()
+fn parse_report(report: &str) -> Vec<i32> {
+    report.split_whitespace().map(|x| x.parse::<i32>().unwrap()).collect()
 }
 
 fn analyze(report: &Vec<i32>) -> Vec<(Change, i32)> {
@@ -32,12 +29,7 @@ fn analyze(report: &Vec<i32>) -> Vec<(Change, i32)> {
 fn is_safe(reports: &Vec<i32>) -> bool {
     let windows = analyze(reports);
 
-    let unidirectional = windows
-        .iter()
-        .map(|(first, _)| first)
-        .collect::<std::collections::HashSet<_>>()
-        .len()
-        == 1;
+    let unidirectional = windows.iter().map(|(first, _)| first).collect::<std::collections::HashSet<_>>().len() == 1;
 
     let within_tolerance = windows.iter().map(|(_, second)| second).all(|x| *x < 4);
 
@@ -52,43 +44,55 @@ fn is_safe_with_damper(reports: &Vec<i32>) -> bool {
     })
 }
 
-pub fn part1(input: &Vec<String>) -> i32 {
-    let reports = input.iter().map(|x| parse_report(x)).collect::<Vec<_>>();
+fn parse(input: &aoc::Input) -> Vec<Vec<i32>> {
+    input.lines().iter().map(|x| parse_report(x)).collect()
+}
+
+pub fn part1(reports: &Vec<Vec<i32>>) -> i32 {
     reports.iter().filter(|x| is_safe(x)).count() as i32
 }
 
-pub fn part2(_input: &Vec<String>) -> i32 {
-    let reports = _input.iter().map(|x| parse_report(x)).collect::<Vec<_>>();
+pub fn part2(reports: &Vec<Vec<i32>>) -> i32 {
     reports.iter().filter(|x| is_safe_with_damper(x)).count() as i32
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day2;
+
+impl aoc::Solution for Day2 {
+    type Input = Vec<Vec<i32>>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    fn get_sample() -> Vec<String> {
-        r#"7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9"#
-            .lines()
-            .map(|x| x.to_string())
-            .collect()
+    fn year(&self) -> i32 {
+        2024
     }
 
-    #[test]
-    fn sample_first_passes() {
-        let sample = get_sample();
-        let result = part1(&sample);
-        assert_eq!(result, 2);
+    fn day(&self) -> i32 {
+        2
     }
 
-    #[test]
-    fn sample_second_passes() {
-        let sample = get_sample();
-        let result = part2(&sample);
-        assert_eq!(result, 4);
+    fn title(&self) -> &'static str {
+        "Red-Nosed Reports"
     }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day2);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day2, "sample.dat", part1 = 2, part2 = 4);
 }
@@ -0,0 +1,94 @@
+use regex::Regex;
+#[derive(Debug)]
+pub struct Operation {
+    op: String,
+    x: i32,
+    y: i32,
+    /// Whether the most recent `do()` or `don't()` before the operation enabled it.
+    enabled: bool,
+}
+
+impl Operation {
+    fn execute(&self) -> i32 {
+        match self.op.as_str() {
+            "mul" => self.x * self.y,
+            _ => 0,
+        }
+    }
+}
+
+fn parse_operands(memory: &[&str]) -> Vec<Operation> {
+    let re = Regex::new(r"(?P<op>mul)\((?P<x>\d{1,3}),(?P<y>\d{1,3})\)|(?P<do>do\(\))|(?P<dont>don't\(\))").unwrap();
+    let mut enabled = true;
+
+    // a do() or don't() stays in effect across lines
+    memory
+        .iter()
+        .flat_map(|line| re.captures_iter(line))
+        .filter_map(|cap| {
+            if cap.name("do").is_some() {
+                enabled = true;
+                return None;
+            }
+            if cap.name("dont").is_some() {
+                enabled = false;
+                return None;
+            }
+
+            let op = cap.name("op").unwrap().as_str().to_string();
+            let x = cap.name("x").unwrap().as_str().parse::<i32>().unwrap();
+            let y = cap.name("y").unwrap().as_str().parse::<i32>().unwrap();
+            Some(Operation { op, x, y, enabled })
+        })
+        .collect()
+}
+
+pub fn part1(input: &[Operation]) -> i32 {
+    input.iter().map(|o| o.execute()).sum()
+}
+
+pub fn part2(input: &[Operation]) -> i32 {
+    input.iter().filter(|o| o.enabled).map(|o| o.execute()).sum()
+}
+
+pub struct Day3;
+
+impl aoc::Solution for Day3 {
+    type Input = Vec<Operation>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        3
+    }
+
+    fn title(&self) -> &'static str {
+        "Mull It Over"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse_operands(&input.lines())
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day3);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day3, "sample.dat", part1 = 161);
+    aoc::sample_test!(conditionals: Day3, "sample2.dat", part2 = 48);
+}
@@ -1,9 +1,3 @@
-#[allow(dead_code)]
-fn pretty_print(matrix: &Vec<Vec<char>>) {
-    for row in matrix {
-        println!("{:?}", row.iter().collect::<String>());
-    }
-}
 fn count_slice(slice: &[char]) -> i32 {
     let mut count = 0;
     for word in slice.windows(4) {
@@ -20,10 +14,7 @@ fn count_horizontal(matrix: &Vec<Vec<char>>) -> i32 {
 }
 
 fn count_horizontal_inverted(matrix: &Vec<Vec<char>>) -> i32 {
-    matrix
-        .iter()
-        .map(|row| count_slice(&row.iter().rev().cloned().collect::<Vec<char>>()))
-        .sum()
+    matrix.iter().map(|row| count_slice(&row.iter().rev().cloned().collect::<Vec<char>>())).sum()
 }
 
 fn transpose(matrix: &Vec<Vec<char>>) -> Vec<Vec<char>> {
@@ -65,10 +56,7 @@ fn count_diagonal(matrix: &Vec<Vec<char>>) -> i32 {
 }
 
 fn count_reverse_diagonal(matrix: &Vec<Vec<char>>) -> i32 {
-    let reversed = matrix
-        .iter()
-        .map(|row| row.iter().rev().cloned().collect())
-        .collect();
+    let reversed = matrix.iter().map(|row| row.iter().rev().cloned().collect()).collect();
     count_diagonal(&reversed)
 }
 
@@ -83,21 +71,22 @@ fn count_reverse_diagonal_inverted(matrix: &Vec<Vec<char>>) -> i32 {
     count_diagonal(&flipped)
 }
 
-pub fn part1(input: &Vec<String>) -> i32 {
-    let matrix = input.iter().map(|line| line.chars().collect()).collect();
-
-    count_horizontal(&matrix)
-        + count_horizontal_inverted(&matrix)
-        + count_vertical(&matrix)
-        + count_vertical_inverted(&matrix)
-        + count_diagonal(&matrix)
-        + count_reverse_diagonal(&matrix)
-        + count_diagonal_inverted(&matrix)
-        + count_reverse_diagonal_inverted(&matrix)
+fn parse(input: &aoc::Input) -> Vec<Vec<char>> {
+    input.grid()
+}
+
+pub fn part1(matrix: &Vec<Vec<char>>) -> i32 {
+    count_horizontal(matrix)
+        + count_horizontal_inverted(matrix)
+        + count_vertical(matrix)
+        + count_vertical_inverted(matrix)
+        + count_diagonal(matrix)
+        + count_reverse_diagonal(matrix)
+        + count_diagonal_inverted(matrix)
+        + count_reverse_diagonal_inverted(matrix)
 }
 
-pub fn part2(input: &Vec<String>) -> i32 {
-    let matrix: Vec<Vec<char>> = input.iter().map(|line| line.chars().collect()).collect();
+pub fn part2(matrix: &Vec<Vec<char>>) -> i32 {
     let mut count = 0;
     let rows = matrix.len();
     let cols = matrix[0].len();
@@ -124,101 +113,107 @@ pub fn part2(input: &Vec<String>) -> i32 {
     count
 }
 
+pub struct Day4;
+
+impl aoc::Solution for Day4 {
+    type Input = Vec<Vec<char>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        4
+    }
+
+    fn title(&self) -> &'static str {
+        "Ceres Search"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day4);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn get_sample() -> Vec<String> {
-        r#"MMMSXXMASM
-MSAMXMSMSA
-AMXSXMAAMM
-MSAMASMSMX
-XMASAMXAMM
-XXAMMXXAMA
-SMSMSASXSS
-SAXAMASAAA
-MAMMMXMMMM
-MXMXAXMASX"#
-            .lines()
-            .map(|x| x.to_string())
-            .collect()
-    }
-
     #[test]
     fn horizontal_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_horizontal(&matrix);
         assert_eq!(result, 3);
     }
 
     #[test]
     fn horizontal_inverse_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_horizontal_inverted(&matrix);
         assert_eq!(result, 2);
     }
 
     #[test]
     fn vertical_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_vertical(&matrix);
         assert_eq!(result, 1);
     }
 
     #[test]
     fn vertical_inverted_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_vertical_inverted(&matrix);
         assert_eq!(result, 2);
     }
 
     #[test]
     fn diagonal_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_diagonal(&matrix);
         assert_eq!(result, 1);
     }
 
     #[test]
     fn reverse_diagonal_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_reverse_diagonal(&matrix);
         assert_eq!(result, 1);
     }
 
     #[test]
     fn diagonal_inverted_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_diagonal_inverted(&matrix);
         assert_eq!(result, 4);
     }
 
     #[test]
     fn reverse_diagonal_inverted_valid() {
-        let sample = get_sample();
-        let matrix = sample.iter().map(|line| line.chars().collect()).collect();
+        let sample = aoc::sample!("sample.dat");
+        let matrix = sample.grid();
         let result = count_reverse_diagonal_inverted(&matrix);
         assert_eq!(result, 4);
     }
 
-    #[test]
-    fn sample_part1_valid() {
-        let sample = get_sample();
-        let result = part1(&sample);
-        assert_eq!(result, 18);
-    }
-
-    #[test]
-    fn sample_part2_valid() {
-        let sample = get_sample();
-        let result = part2(&sample);
-        assert_eq!(result, 9);
-    }
+    aoc::sample_test!(Day4, "sample.dat", part1 = 18, part2 = 9);
 }
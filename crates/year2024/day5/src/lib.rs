@@ -1,15 +1,10 @@
 use std::collections::HashMap;
 
-fn get_rules(input: &[String]) -> HashMap<i32, Vec<i32>> {
+fn get_rules(input: &aoc::Input) -> HashMap<i32, Vec<i32>> {
     let mut rules: HashMap<i32, Vec<i32>> = HashMap::new();
 
-    for line in input {
-        let [page, dependent]: [i32; 2] = line
-            .split("|")
-            .map(|n| n.parse::<i32>().unwrap())
-            .collect::<Vec<_>>()
-            .try_into()
-            .unwrap();
+    for line in input.lines() {
+        let [page, dependent]: [i32; 2] = line.split("|").map(|n| n.parse::<i32>().unwrap()).collect::<Vec<_>>().try_into().unwrap();
 
         if rules.contains_key(&page) {
             let rule = rules.get_mut(&page).unwrap();
@@ -21,31 +16,27 @@ fn get_rules(input: &[String]) -> HashMap<i32, Vec<i32>> {
     rules
 }
 
-fn parse(input: &Vec<String>) -> (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>) {
-    let divider = input.iter().position(|line| line == "").unwrap();
-    let rules = get_rules(&input[0..divider]);
+fn parse(input: &aoc::Input) -> (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>) {
+    let sections = input.sections();
+    let rules = get_rules(sections.first().expect("Missing the page ordering rules"));
 
-    let updates: Vec<Vec<i32>> = input[divider + 1..]
+    let updates: Vec<Vec<i32>> = sections
+        .get(1)
+        .expect("Missing the updates, expected them after a blank line following the page ordering rules")
+        .lines()
         .iter()
-        .map(|line| {
-            line.split(',')
-                .map(|n| n.parse::<i32>().unwrap())
-                .collect::<Vec<i32>>()
-        })
+        .map(|line| line.split(',').map(|n| n.parse::<i32>().unwrap()).collect::<Vec<i32>>())
         .collect();
 
     (rules, updates)
 }
 
 fn score_updates(updates: Vec<Vec<i32>>) -> i32 {
-    updates
-        .iter()
-        .map(|update| update.iter().nth(update.len() / 2).unwrap())
-        .sum::<i32>()
+    updates.iter().map(|update| update.iter().nth(update.len() / 2).unwrap()).sum::<i32>()
 }
 
-pub fn part1(input: &Vec<String>) -> i32 {
-    let (rules, updates) = parse(input);
+pub fn part1(input: &(HashMap<i32, Vec<i32>>, Vec<Vec<i32>>)) -> i32 {
+    let (rules, updates) = input;
 
     let valid: Vec<Vec<i32>> = updates
         .iter()
@@ -73,8 +64,8 @@ pub fn part1(input: &Vec<String>) -> i32 {
     score_updates(valid)
 }
 
-pub fn part2(input: &Vec<String>) -> i32 {
-    let (rules, updates) = parse(input);
+pub fn part2(input: &(HashMap<i32, Vec<i32>>, Vec<Vec<i32>>)) -> i32 {
+    let (rules, updates) = input;
 
     let invalid: Vec<Vec<i32>> = updates
         .iter()
@@ -99,7 +90,7 @@ pub fn part2(input: &Vec<String>) -> i32 {
         .map(|update| update.clone())
         .collect();
 
-    let reordered = invalid.iter().map(|p| reorder(p, &rules)).collect();
+    let reordered = invalid.iter().map(|p| reorder(p, rules)).collect();
 
     score_updates(reordered)
 }
@@ -123,11 +114,11 @@ fn reorder(pages: &Vec<i32>, proceeds: &HashMap<i32, Vec<i32>>) -> Vec<i32> {
         let mut index = 0;
         result.iter().enumerate().for_each(|(i, p)| {
             if follows.contains_key(&page) {
-                    let antecedents = follows.get(&page).unwrap();
-                    if antecedents.contains(&p) {
-                        index = i + 1;
-                    }
+                let antecedents = follows.get(&page).unwrap();
+                if antecedents.contains(&p) {
+                    index = i + 1;
                 }
+            }
         });
 
         result.insert(index, page);
@@ -136,28 +127,43 @@ fn reorder(pages: &Vec<i32>, proceeds: &HashMap<i32, Vec<i32>>) -> Vec<i32> {
     result
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day5;
+
+impl aoc::Solution for Day5 {
+    type Input = (HashMap<i32, Vec<i32>>, Vec<Vec<i32>>);
+    type Part1 = i32;
+    type Part2 = i32;
 
-    fn get_sample() -> Vec<String> {
-        include_str!("sample.dat")
-            .lines()
-            .map(|line| line.to_string())
-            .collect()
+    fn year(&self) -> i32 {
+        2024
     }
 
-    #[test]
-    fn sample_part1_valid() {
-        let sample = get_sample();
-        let result = part1(&sample);
-        assert_eq!(result, 143);
+    fn day(&self) -> i32 {
+        5
     }
 
-    #[test]
-    fn sample_part2_valid() {
-        let sample = get_sample();
-        let result = part2(&sample);
-        assert_eq!(result, 123);
+    fn title(&self) -> &'static str {
+        "Print Queue"
     }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        parse(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day5);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day5, "sample.dat", part1 = 143, part2 = 123);
 }
@@ -1,5 +1,5 @@
 #[derive(Clone, PartialEq, Eq)]
-enum Location {
+pub enum Location {
     Empty,       // .
     Obstacle,    // #
     Patrolled,   // X
@@ -35,12 +35,12 @@ impl std::fmt::Debug for Location {
     }
 }
 
-fn build_map(input: &Vec<String>) -> (Vec<Vec<Location>>, (usize, usize, Location)) {
+fn build_map(input: &aoc::Input) -> (Vec<Vec<Location>>, (usize, usize, Location)) {
     let mut map = Vec::new();
     let mut start = (0, 0, Location::Empty);
-    for line in input {
+    for line in input.grid() {
         let mut row = Vec::new();
-        for c in line.chars() {
+        for c in line {
             match c {
                 '.' => row.push(Location::Empty),
                 '#' => row.push(Location::Obstacle),
@@ -136,10 +136,7 @@ fn walk(map: &Vec<Vec<Location>>, start: &(usize, usize, Location)) -> (Vec<Vec<
                     true
                 }
             }
-            _ => panic!(
-                "The map is in an invalid state.  Unexpected character {}",
-                new_map[current.0][current.1]
-            ),
+            _ => panic!("The map is in an invalid state.  Unexpected character {}", new_map[current.0][current.1]),
         };
 
         steps += 1;
@@ -161,22 +158,19 @@ fn walk(map: &Vec<Vec<Location>>, start: &(usize, usize, Location)) -> (Vec<Vec<
 }
 
 fn count_state(map: &Vec<Vec<Location>>, state: Location) -> i32 {
-    map.iter()
-        .map(|row| row.iter().filter(|x| x == &&state))
-        .flatten()
-        .count() as i32
+    map.iter().map(|row| row.iter().filter(|x| x == &&state)).flatten().count() as i32
 }
 
-pub fn part1(input: &Vec<String>) -> i32 {
-    let (map, start) = build_map(input);
-    let (patrolled, _) = walk(&map, &start);
+pub fn part1(input: &(Vec<Vec<Location>>, (usize, usize, Location))) -> i32 {
+    let (map, start) = input;
+    let (patrolled, _) = walk(map, start);
 
     count_state(&patrolled, Location::Patrolled)
 }
 
-pub fn part2(input: &Vec<String>) -> i32 {
-    let (map, start) = build_map(input);
-    let (mut patrolled, _) = walk(&map, &start);
+pub fn part2(input: &(Vec<Vec<Location>>, (usize, usize, Location))) -> i32 {
+    let (map, start) = input;
+    let (mut patrolled, _) = walk(map, start);
 
     let mut options = 0;
 
@@ -187,7 +181,7 @@ pub fn part2(input: &Vec<String>) -> i32 {
             if patrolled[row][col] == Location::Patrolled {
                 let mut map2 = map.clone();
                 map2[row][col] = Location::Obstacle;
-                options += match walk(&map2, &start) {
+                options += match walk(&map2, start) {
                     (_, true) => 1,
                     _ => 0,
                 }
@@ -198,28 +192,43 @@ pub fn part2(input: &Vec<String>) -> i32 {
     options
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+pub struct Day6;
 
-    fn get_sample() -> Vec<String> {
-        include_str!("sample.dat")
-            .lines()
-            .map(|line| line.to_string())
-            .collect()
+impl aoc::Solution for Day6 {
+    type Input = (Vec<Vec<Location>>, (usize, usize, Location));
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn year(&self) -> i32 {
+        2024
     }
 
-    #[test]
-    fn sample_part1_valid() {
-        let sample = get_sample();
-        let result = part1(&sample);
-        assert_eq!(result, 41);
+    fn day(&self) -> i32 {
+        6
     }
 
-    #[test]
-    fn sample_part2_valid() {
-        let sample = get_sample();
-        let result = part2(&sample);
-        assert_eq!(result, 6);
+    fn title(&self) -> &'static str {
+        "Guard Gallivant"
     }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        build_map(input)
+    }
+
+    fn part1(&self, input: &Self::Input) -> i32 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i32 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day6);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::sample_test!(Day6, "sample.dat", part1 = 41, part2 = 6);
 }
@@ -1,5 +1,5 @@
 #[derive(Clone, PartialEq, Eq)]
-enum FileRef {
+pub enum FileRef {
     File(i64, usize),
     Space,
 }
@@ -23,20 +23,14 @@ impl std::fmt::Debug for FileRef {
     }
 }
 
-fn read_map(input: &String) -> Vec<FileRef> {
+fn read_map(input: &str) -> Vec<FileRef> {
     let mut id = 0;
     let mut files = Vec::new();
 
     for chunk in input.as_bytes().chunks(2) {
-        let size = std::str::from_utf8(&chunk[0..1])
-            .unwrap()
-            .parse::<usize>()
-            .unwrap();
+        let size = std::str::from_utf8(&chunk[0..1]).unwrap().parse::<usize>().unwrap();
         let space = match &chunk.len() {
-            2 => std::str::from_utf8(&chunk[1..2])
-                .unwrap()
-                .parse::<usize>()
-                .unwrap_or(0),
+            2 => std::str::from_utf8(&chunk[1..2]).unwrap().parse::<usize>().unwrap_or(0),
             _ => 0,
         };
         for _ in 0..size {
@@ -64,7 +58,6 @@ fn compress_blocks(disk: &Vec<FileRef>) -> Vec<FileRef> {
                     write += 1;
                 }
                 if write >= read {
-                    println!("midpoint detected: Write: {write}, Read: {read}");
                     break;
                 }
                 compressed[write] = FileRef::File(id, size);
@@ -86,18 +79,11 @@ fn compress_first_fit(disk: &Vec<FileRef>) -> Vec<FileRef> {
         read -= match compressed[read] {
             FileRef::Space => 1,
             FileRef::File(id, size) => {
-                while !compressed[write..write + size]
-                    .iter()
-                    .all(|x| *x == FileRef::Space)
-                    && write < read
-                    && write + size < disk.len()
-                {
+                while !compressed[write..write + size].iter().all(|x| *x == FileRef::Space) && write < read && write + size < disk.len() {
                     write += 1;
                 }
 
-                if compressed[write..write + size]
-                    .iter()
-                    .all(|x| *x == FileRef::Space) {
+                if compressed[write..write + size].iter().all(|x| *x == FileRef::Space) {
                     for i in 0..size {
                         compressed[write + i] = FileRef::File(id, size);
                         compressed[read - i] = FileRef::Space;
@@ -120,77 +106,77 @@ fn calculate_checksum(disk: &Vec<FileRef>) -> i64 {
     })
 }
 
-pub fn part1(input: &Vec<String>) -> i64 {
-    let files = read_map(&input.first().unwrap());
-    let compressed = compress_blocks(&files);
+pub fn part1(files: &Vec<FileRef>) -> i64 {
+    let compressed = compress_blocks(files);
     calculate_checksum(&compressed)
 }
 
-pub fn part2(input: &Vec<String>) -> i64 {
-    let files = read_map(&input.first().unwrap());
-    let compressed = compress_first_fit(&files);
+pub fn part2(files: &Vec<FileRef>) -> i64 {
+    let compressed = compress_first_fit(files);
     calculate_checksum(&compressed)
 }
 
+pub struct Day9;
+
+impl aoc::Solution for Day9 {
+    type Input = Vec<FileRef>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn year(&self) -> i32 {
+        2024
+    }
+
+    fn day(&self) -> i32 {
+        9
+    }
+
+    fn title(&self) -> &'static str {
+        "Disk Fragmenter"
+    }
+
+    fn parse(&self, input: &aoc::Input) -> Self::Input {
+        read_map(input.first_line())
+    }
+
+    fn part1(&self, input: &Self::Input) -> i64 {
+        part1(input)
+    }
+
+    fn part2(&self, input: &Self::Input) -> i64 {
+        part2(input)
+    }
+}
+
+aoc::register!(Day9);
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    fn get_sample() -> Vec<String> {
-        include_str!("sample.dat")
-            .lines()
-            .map(|line| line.to_string())
-            .collect()
-    }
-
     #[test]
     fn disk_layout_is_valid() {
-        let input = get_sample();
-        let files = read_map(&input.first().unwrap());
+        let input = aoc::sample!("sample.dat");
+        let files = read_map(input.first_line());
 
-        assert_eq!(
-            files.iter().map(|f| format!("{:?}", f)).collect::<String>(),
-            "00...111...2...333.44.5555.6666.777.888899"
-        );
+        assert_eq!(files.iter().map(|f| format!("{:?}", f)).collect::<String>(), "00...111...2...333.44.5555.6666.777.888899");
     }
 
     #[test]
     fn compress_blocks_is_valid() {
-        let input = get_sample();
-        let files = read_map(&input.first().unwrap());
+        let input = aoc::sample!("sample.dat");
+        let files = read_map(input.first_line());
         let compressed = compress_blocks(&files);
-        assert_eq!(
-            compressed
-                .iter()
-                .map(|f| format!("{:?}", f))
-                .collect::<String>(),
-            "0099811188827773336446555566.............."
-        );
+        assert_eq!(compressed.iter().map(|f| format!("{:?}", f)).collect::<String>(), "0099811188827773336446555566..............");
     }
 
     #[test]
     fn compress_first_fit_is_valid() {
-        let input = get_sample();
-        let files = read_map(&input.first().unwrap());
+        let input = aoc::sample!("sample.dat");
+        let files = read_map(input.first_line());
         let compressed = compress_first_fit(&files);
-        assert_eq!(
-            compressed
-                .iter()
-                .map(|f| format!("{:?}", f))
-                .collect::<String>(),
-            "00992111777.44.333....5555.6666.....8888.."
-        );
+        assert_eq!(compressed.iter().map(|f| format!("{:?}", f)).collect::<String>(), "00992111777.44.333....5555.6666.....8888..");
     }
 
-    #[test]
-    fn sample_part1() {
-        let input = get_sample();
-        assert_eq!(part1(&input), 1928);
-    }
-
-    #[test]
-    fn sample_part2() {
-        let input = get_sample();
-        assert_eq!(part2(&input), 2858);
-    }
+    aoc::sample_test!(Day9, "sample.dat", part1 = 1928, part2 = 2858);
 }